@@ -1,9 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-const TYPE_NAME: &str = "angle";
+use crate::validate::Problems;
+use crate::{Tagged, Validate};
 
 /// A structure representing an angle in radians.
 #[derive(Serialize, Deserialize, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "AngleCbor", into = "AngleCbor")]
 pub struct Angle {
     radians: f64,
@@ -55,12 +57,109 @@ impl Angle {
     pub const fn deg(&self) -> f64 {
         self.radians * 180.0 / std::f64::consts::PI
     }
+
+    /// Returns the angle normalized into the range `[0, 2π)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Angle;
+    ///
+    /// let angle = Angle::new(-std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(angle.normalized().deg(), 270.0);
+    /// ```
+    pub fn normalized(&self) -> Self {
+        Self::new(self.radians.rem_euclid(std::f64::consts::TAU))
+    }
+
+    /// Creates an angle from a compass bearing in degrees.
+    ///
+    /// Bearings are measured clockwise from north, while angles follow the
+    /// mathematical convention of [`Angle::compass_label`]. The result is
+    /// normalized into `[0, 2π)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Angle;
+    ///
+    /// assert_eq!(Angle::from_compass(0.0).deg(), 90.0);
+    /// assert_eq!(Angle::from_compass(90.0).deg(), 0.0);
+    /// ```
+    pub fn from_compass(bearing_deg: f64) -> Self {
+        Self::new((90.0 - bearing_deg).to_radians()).normalized()
+    }
+
+    /// Returns the compass bearing of this angle in degrees, in `[0, 360)`.
+    ///
+    /// This is the inverse of [`Angle::from_compass`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Angle;
+    ///
+    /// assert_eq!(Angle::new(std::f64::consts::PI).to_compass(), 270.0);
+    /// ```
+    pub fn to_compass(&self) -> f64 {
+        (90.0 - self.deg()).rem_euclid(360.0)
+    }
+
+    /// Returns the nearest of the 16 compass points for this angle.
+    ///
+    /// The angle is interpreted in the mathematical convention: `0°` points
+    /// east and angles grow counterclockwise, so `90°` points north.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Angle;
+    ///
+    /// assert_eq!(Angle::new(0.0).compass_label(), "E");
+    /// assert_eq!(Angle::new(std::f64::consts::FRAC_PI_2).compass_label(), "N");
+    /// assert_eq!(Angle::new(std::f64::consts::FRAC_PI_4).compass_label(), "NE");
+    /// ```
+    pub fn compass_label(&self) -> &'static str {
+        const LABELS: [&str; 16] = [
+            "E", "ENE", "NE", "NNE", "N", "NNW", "NW", "WNW", "W", "WSW", "SW", "SSW", "S", "SSE",
+            "SE", "ESE",
+        ];
+
+        let sector = (self.normalized().deg() / 22.5).round() as usize % LABELS.len();
+
+        LABELS[sector]
+    }
+}
+
+impl Tagged for Angle {
+    const TYPE_NAME: &'static str = "angle";
+}
+
+impl Validate for Angle {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.check(self.radians.is_finite(), || {
+            format!("{} is not finite", self.radians)
+        });
+        problems.finish()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct AngleCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Angle, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Angle>")
+    )]
     typwire_type: String,
+    #[cfg_attr(feature = "fixed", serde(with = "crate::fixed"))]
+    #[cfg_attr(all(feature = "fixed", feature = "schemars"), schemars(with = "i32"))]
     radians: f64,
 }
 
@@ -68,7 +167,7 @@ struct AngleCbor {
 impl From<Angle> for AngleCbor {
     fn from(angle: Angle) -> Self {
         AngleCbor {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Angle::TYPE_NAME.to_string(),
             radians: angle.radians,
         }
     }
@@ -79,13 +178,45 @@ impl TryFrom<AngleCbor> for Angle {
     type Error = String;
 
     fn try_from(value: AngleCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for Angle: {}",
-                value.typwire_type
-            ));
-        }
-
         Ok(Self::new(value.radians))
     }
 }
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AngleOrDegrees {
+    Angle(Angle),
+    Degrees(f64),
+}
+
+/// Deserializes an angle from either its tagged encoding or a bare number in degrees.
+pub(crate) fn deserialize_angle_or_degrees<'de, D>(deserializer: D) -> Result<Angle, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match AngleOrDegrees::deserialize(deserializer)? {
+        AngleOrDegrees::Angle(angle) => angle,
+        AngleOrDegrees::Degrees(degrees) => Angle::new(degrees.to_radians()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compass() {
+        let north = Angle::from_compass(0.0);
+        assert_eq!(north.compass_label(), "N");
+        assert_eq!(north.to_compass(), 0.0);
+
+        let east = Angle::from_compass(90.0);
+        assert_eq!(east.compass_label(), "E");
+        assert_eq!(east.to_compass(), 90.0);
+
+        for bearing in [0.0, 45.0, 135.0, 200.0, 359.0] {
+            assert!((Angle::from_compass(bearing).to_compass() - bearing).abs() < 1e-9);
+        }
+        assert!((Angle::from_compass(-90.0).to_compass() - 270.0).abs() < 1e-9);
+    }
+}
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Ratio;
+use crate::validate::Problems;
+use crate::{Angle, Ratio, Validate};
 
 /// A structure representing the center of a gradient, defined by x and y ratios.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Center {
     pub x: Ratio,
     pub y: Ratio,
@@ -29,4 +31,112 @@ impl Center {
     pub const fn new(x: Ratio, y: Ratio) -> Self {
         Self { x, y }
     }
+
+    /// Creates a new `Center` from polar coordinates relative to `origin`.
+    ///
+    /// The ratio space is treated as a unit square and the angle is measured
+    /// from the positive x-axis towards the positive y-axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The reference point of the polar coordinates.
+    /// * `radius` - The distance from `origin`.
+    /// * `angle` - The direction from `origin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Center, Ratio};
+    ///
+    /// let origin = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+    /// let center = Center::from_polar(&origin, Ratio::new(0.25), Angle::new(0.0));
+    /// assert_eq!(center, Center::new(Ratio::new(0.75), Ratio::new(0.5)));
+    /// ```
+    pub fn from_polar(origin: &Center, radius: Ratio, angle: Angle) -> Self {
+        let (sin, cos) = angle.rad().sin_cos();
+
+        Self::new(
+            Ratio::new(origin.x.ratio + radius.ratio * cos),
+            Ratio::new(origin.y.ratio + radius.ratio * sin),
+        )
+    }
+
+    /// Returns the polar coordinates of this point relative to `origin`.
+    ///
+    /// The angle follows the convention of [`Center::from_polar`] and is
+    /// normalized into `[0, 2π)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The reference point of the polar coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Center, Ratio};
+    ///
+    /// let origin = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+    /// let center = Center::new(Ratio::new(0.5), Ratio::new(0.75));
+    /// assert_eq!(
+    ///     center.to_polar(&origin),
+    ///     (Ratio::new(0.25), Angle::new(std::f64::consts::FRAC_PI_2))
+    /// );
+    /// ```
+    pub fn to_polar(&self, origin: &Center) -> (Ratio, Angle) {
+        let dx = self.x.ratio - origin.x.ratio;
+        let dy = self.y.ratio - origin.y.ratio;
+
+        (
+            Ratio::new(dx.hypot(dy)),
+            Angle::new(dy.atan2(dx)).normalized(),
+        )
+    }
+
+    /// Returns the direction from this center to `point`.
+    ///
+    /// As in Typst, the y-axis points down and angles grow clockwise, so a point
+    /// with a larger y ratio lies below the center at `90°`. Unlike [`Center::to_polar`], the angle is not
+    /// normalized and lies in `(-π, π]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to compute the direction to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Center, Ratio};
+    ///
+    /// let center = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+    /// let point = Center::new(Ratio::new(0.5), Ratio::new(0.25));
+    /// assert_eq!(center.angle_to(&point).deg(), -90.0);
+    /// ```
+    pub fn angle_to(&self, point: &Center) -> Angle {
+        Angle::new((point.y.ratio - self.y.ratio).atan2(point.x.ratio - self.x.ratio))
+    }
+}
+
+impl Validate for Center {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.nested("x", &self.x);
+        problems.nested("y", &self.y);
+        problems.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_to() {
+        let center = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+
+        let right = Center::new(Ratio::new(0.75), Ratio::new(0.5));
+        assert_eq!(center.angle_to(&right).deg(), 0.0);
+
+        let below = Center::new(Ratio::new(0.5), Ratio::new(0.75));
+        assert_eq!(center.angle_to(&below).deg(), 90.0);
+    }
 }
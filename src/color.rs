@@ -1,6 +1,18 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{Angle, Gradient, Ratio};
+use crate::validate::Problems;
+use crate::{Angle, ColorSpace, Gradient, Ratio, Validate, Value};
+
+/// Tolerance used when checking whether a color lies within the RGB gamut.
+const GAMUT_EPSILON: f64 = 1e-9;
+
+/// The largest chroma of OKLCH colors in Typst.
+const MAX_CHROMA: f64 = 0.4;
+
+/// Chroma below which a color is considered achromatic (gray).
+const ACHROMATIC_EPSILON: f64 = 1e-6;
 
 pub const BLACK: Color = Color::Luma(Luma::new(Ratio::new(0.0), Ratio::new(1.0)));
 pub const GRAY: Color = Color::Luma(Luma::new(Ratio::new(170.0 / 255.0), Ratio::new(1.0)));
@@ -92,8 +104,30 @@ pub const LIME: Color = Color::Rgb(Rgb::new(
     Ratio::new(1.0),
 ));
 
+/// All predefined colors, in the order they are declared.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::color;
+///
+/// assert!(color::PALETTE.contains(&color::RED));
+/// ```
+pub const PALETTE: [Color; 18] = [
+    BLACK, GRAY, SILVER, WHITE, NAVY, BLUE, AQUA, TEAL, EASTERN, PURPLE, FUCHSIA, MAROON, RED,
+    ORANGE, YELLOW, OLIVE, GREEN, LIME,
+];
+
+/// The names of the colors in [`PALETTE`], in the same order.
+const PALETTE_NAMES: [&str; 18] = [
+    "black", "gray", "silver", "white", "navy", "blue", "aqua", "teal", "eastern", "purple",
+    "fuchsia", "maroon", "red", "orange", "yellow", "olive", "green", "lime",
+];
+
 /// A grayscale color with lightness and alpha (transparency).
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "LumaCbor")]
 pub struct Luma {
     /// The lightness component of the color.
     pub lightness: Ratio,
@@ -116,10 +150,26 @@ impl Luma {
     pub const fn new(lightness: Ratio, alpha: Ratio) -> Self {
         Self { lightness, alpha }
     }
+
+    /// Converts the color to the standard RGB color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Luma, Ratio};
+    ///
+    /// let rgb = Luma::new(Ratio::new(0.5), Ratio::new(1.0)).to_rgb();
+    /// assert_eq!(rgb.g, Ratio::new(0.5));
+    /// ```
+    pub const fn to_rgb(&self) -> Rgb {
+        Rgb::new(self.lightness, self.lightness, self.lightness, self.alpha)
+    }
 }
 
 /// A color in the OKLab color space.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "OklabCbor")]
 pub struct Oklab {
     /// The lightness component of the color.
     pub lightness: Ratio,
@@ -155,10 +205,77 @@ impl Oklab {
             alpha,
         }
     }
+
+    /// Converts the color to the linear RGB color space.
+    ///
+    /// The result may lie outside of the RGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Oklab, Ratio};
+    ///
+    /// let o = Oklab::new(
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert!((o.to_linear_rgb().g.ratio - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        let (lightness, a, b) = (self.lightness.ratio, self.a.ratio, self.b.ratio);
+
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        LinearRgb::new(
+            Ratio::new(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            Ratio::new(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            Ratio::new(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+            self.alpha,
+        )
+    }
+
+    /// Converts the color to the OKLCH color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Oklab, Ratio};
+    ///
+    /// let o = Oklab::new(
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.1),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let lch = o.to_oklch();
+    /// assert!((lch.chroma.ratio - 0.1).abs() < 1e-9);
+    /// assert!((lch.hue.deg() - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn to_oklch(&self) -> Oklch {
+        let chroma = self.a.ratio.hypot(self.b.ratio);
+
+        // Achromatic colors have no meaningful hue, report a stable one instead.
+        if chroma < ACHROMATIC_EPSILON {
+            return Oklch::new(self.lightness, Ratio::new(0.0), Angle::new(0.0), self.alpha);
+        }
+
+        Oklch::new(
+            self.lightness,
+            Ratio::new(chroma),
+            Angle::new(self.b.ratio.atan2(self.a.ratio)),
+            self.alpha,
+        )
+    }
 }
 
 /// A color in the OKLCH color space.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "OklchCbor")]
 pub struct Oklch {
     /// The lightness component of the color.
     pub lightness: Ratio,
@@ -194,10 +311,38 @@ impl Oklch {
             alpha,
         }
     }
+
+    /// Converts the color to the OKLab color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Oklch, Ratio};
+    ///
+    /// let o = Oklch::new(
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(0.1),
+    ///     Angle::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert_eq!(o.to_oklab().a, Ratio::new(0.1));
+    /// ```
+    pub fn to_oklab(&self) -> Oklab {
+        let hue = self.hue.rad();
+
+        Oklab::new(
+            self.lightness,
+            Ratio::new(self.chroma.ratio * hue.cos()),
+            Ratio::new(self.chroma.ratio * hue.sin()),
+            self.alpha,
+        )
+    }
 }
 
 /// A color in the linear RGB color space.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "LinearRgbCbor")]
 pub struct LinearRgb {
     /// The red component of the color.
     pub r: Ratio,
@@ -228,10 +373,70 @@ impl LinearRgb {
     pub const fn new(r: Ratio, g: Ratio, b: Ratio, alpha: Ratio) -> Self {
         Self { r, g, b, alpha }
     }
+
+    /// Converts the color to the standard (gamma-encoded) RGB color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{LinearRgb, Ratio};
+    ///
+    /// let lr = LinearRgb::new(
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let rgb = lr.to_rgb();
+    /// assert!((rgb.r.ratio - 1.0).abs() < 1e-9);
+    /// assert_eq!(rgb.g, Ratio::new(0.0));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb {
+        Rgb::new(
+            Ratio::new(srgb_encode(self.r.ratio)),
+            Ratio::new(srgb_encode(self.g.ratio)),
+            Ratio::new(srgb_encode(self.b.ratio)),
+            self.alpha,
+        )
+    }
+
+    /// Converts the color to the OKLab color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{LinearRgb, Ratio};
+    ///
+    /// let white = LinearRgb::new(
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let oklab = white.to_oklab();
+    /// assert!((oklab.lightness.ratio - 1.0).abs() < 1e-6);
+    /// assert!(oklab.a.ratio.abs() < 1e-6);
+    /// ```
+    pub fn to_oklab(&self) -> Oklab {
+        let (r, g, b) = (self.r.ratio, self.g.ratio, self.b.ratio);
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Oklab::new(
+            Ratio::new(0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s),
+            Ratio::new(1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s),
+            Ratio::new(0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s),
+            self.alpha,
+        )
+    }
 }
 
 /// A color in the standard RGB color space.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RgbCbor")]
 pub struct Rgb {
     /// The red component of the color.
     pub r: Ratio,
@@ -262,163 +467,1843 @@ impl Rgb {
     pub const fn new(r: Ratio, g: Ratio, b: Ratio, alpha: Ratio) -> Self {
         Self { r, g, b, alpha }
     }
-}
 
-/// A color in the CMYK color space.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-pub struct Cmyk {
-    /// The cyan component of the color.
-    pub cyan: Ratio,
-    /// The magenta component of the color.
-    pub magenta: Ratio,
-    /// The yellow component of the color.
-    pub yellow: Ratio,
-    /// The key (black) component of the color.
-    pub key: Ratio,
-}
+    /// Deserializes an Rgb color whose channels may be encoded as bytes.
+    ///
+    /// Some producers encode channels as integers from 0 to 255 instead of
+    /// ratios. Here, integer channels are divided by 255, bare floats are taken
+    /// as ratios, and regular tagged ratios decode as usual. As this is
+    /// ambiguous (an integer `1` is the byte 1, not the ratio 1), it is only
+    /// done on this explicit path, never by [`FromBytes`](crate::FromBytes).
+    ///
+    /// # Errors
+    /// Returns a string error message if the payload is not a map, an integer
+    /// channel lies outside of 0 to 255, or the color fails to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ciborium::cbor;
+    /// use typwire::{Ratio, Rgb};
+    ///
+    /// let value = cbor!({ "r" => 255, "g" => 0.5, "b" => 0, "alpha" => 255 }).unwrap();
+    /// let mut bytes = Vec::new();
+    /// ciborium::into_writer(&value, &mut bytes).unwrap();
+    ///
+    /// let rgb = Rgb::from_bytes_u8_encoded(&bytes).unwrap();
+    /// assert_eq!(rgb, Rgb::new(Ratio::new(1.0), Ratio::new(0.5), Ratio::new(0.0), Ratio::new(1.0)));
+    /// ```
+    pub fn from_bytes_u8_encoded(bytes: &[u8]) -> Result<Rgb, String> {
+        use crate::FromBytes as _;
 
-impl Cmyk {
-    /// Creates a new Cmyk color with the given cyan, magenta, yellow, and key (black) components.
+        let Value::Map(entries) = Value::from_bytes(bytes)? else {
+            return Err("Expected a map of Rgb channels".to_string());
+        };
+
+        let entries = entries
+            .into_iter()
+            .map(|(key, channel)| {
+                let name = key
+                    .as_text()
+                    .filter(|name| ["r", "g", "b", "alpha"].contains(name));
+                let ratio = match (name, channel) {
+                    (Some(name), Value::Integer(byte)) => u8::try_from(byte)
+                        .map(|byte| f64::from(byte) / 255.0)
+                        .map_err(|_| format!("{name}: {} is not a byte", i128::from(byte)))?,
+                    (Some(_), Value::Float(ratio)) => ratio,
+                    (_, channel) => return Ok((key, channel)),
+                };
+                let channel =
+                    Value::serialized(&Ratio::new(ratio)).map_err(crate::value_error_message)?;
+                Ok((key, channel))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Rgb::from_value(Value::Map(entries))
+    }
+
+    /// Converts the color to the linear RGB color space.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typwire::{Cmyk, Ratio};
+    /// use typwire::{Ratio, Rgb};
     ///
-    /// let c = Cmyk::new(
-    ///     Ratio::new(0.0),
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(1.0),
     ///     Ratio::new(0.0),
     ///     Ratio::new(0.0),
     ///     Ratio::new(1.0),
     /// );
-    /// assert_eq!(c.key, Ratio::new(1.0));
+    /// assert!((rgb.to_linear_rgb().r.ratio - 1.0).abs() < 1e-9);
     /// ```
-    pub const fn new(cyan: Ratio, magenta: Ratio, yellow: Ratio, key: Ratio) -> Self {
-        Self {
-            cyan,
-            magenta,
-            yellow,
-            key,
-        }
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        LinearRgb::new(
+            Ratio::new(srgb_decode(self.r.ratio)),
+            Ratio::new(srgb_decode(self.g.ratio)),
+            Ratio::new(srgb_decode(self.b.ratio)),
+            self.alpha,
+        )
     }
-}
-
-/// A color in the HSL (Hue, Saturation, Lightness) color space.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-pub struct Hsl {
-    /// The hue component of the color.
-    pub hue: Angle,
-    /// The saturation component of the color.
-    pub saturation: Ratio,
-    /// The lightness component of the color.
-    pub lightness: Ratio,
-    /// The alpha (transparency) component of the color.
-    pub alpha: Ratio,
-}
 
-impl Hsl {
-    /// Creates a new Hsl color with the given hue, saturation, lightness, and alpha components.
+    /// Converts the color to a grayscale color with the same relative luminance.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typwire::{Angle, Hsl, Ratio};
+    /// use typwire::{Ratio, Rgb};
     ///
-    /// let h = Hsl::new(
-    ///     Angle::new(0.0),
-    ///     Ratio::new(1.0),
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(0.5),
     ///     Ratio::new(0.5),
     ///     Ratio::new(1.0),
     /// );
-    /// assert_eq!(h.saturation, Ratio::new(1.0));
+    /// assert!((rgb.to_luma().lightness.ratio - 0.5).abs() < 1e-9);
     /// ```
-    pub const fn new(hue: Angle, saturation: Ratio, lightness: Ratio, alpha: Ratio) -> Self {
-        Self {
-            hue,
-            saturation,
-            lightness,
-            alpha,
-        }
-    }
-}
+    pub fn to_luma(&self) -> Luma {
+        let linear = self.to_linear_rgb();
+        let luminance = 0.2126 * linear.r.ratio + 0.7152 * linear.g.ratio + 0.0722 * linear.b.ratio;
 
-/// A color in the HSV (Hue, Saturation, Value) color space.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-pub struct Hsv {
-    /// The hue component of the color.
-    pub hue: Angle,
-    /// The saturation component of the color.
-    pub saturation: Ratio,
-    /// The value (brightness) component of the color.
-    pub value: Ratio,
-    /// The alpha (transparency) component of the color.
-    pub alpha: Ratio,
-}
+        Luma::new(Ratio::new(srgb_encode(luminance)), self.alpha)
+    }
 
-impl Hsv {
-    /// Creates a new Hsv color with the given hue, saturation, value, and alpha components.
+    /// Converts the color to the CMYK color space, dropping the alpha component.
     ///
     /// # Examples
     ///
     /// ```
-    /// use typwire::{Angle, Hsv, Ratio};
+    /// use typwire::{Ratio, Rgb};
     ///
-    /// let h = Hsv::new(
-    ///     Angle::new(0.0),
-    ///     Ratio::new(1.0),
+    /// let rgb = Rgb::new(
     ///     Ratio::new(1.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
     ///     Ratio::new(1.0),
     /// );
-    /// assert_eq!(h.value, Ratio::new(1.0));
+    /// let cmyk = rgb.to_cmyk();
+    /// assert_eq!(cmyk.cyan, Ratio::new(0.0));
+    /// assert_eq!(cmyk.magenta, Ratio::new(1.0));
     /// ```
-    pub const fn new(hue: Angle, saturation: Ratio, value: Ratio, alpha: Ratio) -> Self {
-        Self {
-            hue,
-            saturation,
-            value,
-            alpha,
+    pub fn to_cmyk(&self) -> Cmyk {
+        let (r, g, b) = (self.r.ratio, self.g.ratio, self.b.ratio);
+        let white = r.max(g).max(b);
+
+        if white <= 0.0 {
+            return Cmyk::new(
+                Ratio::new(0.0),
+                Ratio::new(0.0),
+                Ratio::new(0.0),
+                Ratio::new(1.0),
+            );
         }
+
+        Cmyk::new(
+            Ratio::new((white - r) / white),
+            Ratio::new((white - g) / white),
+            Ratio::new((white - b) / white),
+            Ratio::new(1.0 - white),
+        )
     }
-}
 
-/// Represents a color in various color spaces.
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-#[serde(tag = "typwire-type")]
-pub enum Color {
-    /// A grayscale color with lightness and alpha (transparency).
-    #[serde(rename = "color-luma")]
-    Luma(Luma),
-    /// A color in the OKLab color space.
-    #[serde(rename = "color-oklab")]
-    Oklab(Oklab),
-    /// A color in the OKLCH color space.
-    #[serde(rename = "color-oklch")]
-    Oklch(Oklch),
-    /// A color in the linear RGB color space.
-    #[serde(rename = "color-linear-rgb")]
-    LinearRgb(LinearRgb),
-    /// A color in the standard RGB color space.
-    #[serde(rename = "color-rgb")]
-    Rgb(Rgb),
-    /// A color in the CMYK color space.
-    #[serde(rename = "color-cmyk")]
-    Cmyk(Cmyk),
-    /// A color in the HSL (Hue, Saturation, Lightness) color space.
-    #[serde(rename = "color-hsl")]
-    Hsl(Hsl),
-    /// A color in the HSV (Hue, Saturation, Value) color space.
-    #[serde(rename = "color-hsv")]
-    Hsv(Hsv),
-}
+    /// Converts the color to the HSL color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Rgb};
+    ///
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let hsl = rgb.to_hsl();
+    /// assert!((hsl.hue.deg() - 120.0).abs() < 1e-9);
+    /// assert_eq!(hsl.lightness, Ratio::new(0.25));
+    /// ```
+    pub fn to_hsl(&self) -> Hsl {
+        let (max, min) = self.extrema();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
 
-// Conversions from structs to Color enum
-impl From<Luma> for Color {
-    fn from(luma: Luma) -> Self {
-        Color::Luma(luma)
+        Hsl::new(
+            self.hue(),
+            Ratio::new(saturation),
+            Ratio::new(lightness),
+            self.alpha,
+        )
     }
-}
 
-impl From<Oklab> for Color {
+    /// Converts the color to the HSV color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Rgb};
+    ///
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let hsv = rgb.to_hsv();
+    /// assert!((hsv.hue.deg() - 240.0).abs() < 1e-9);
+    /// assert_eq!(hsv.value, Ratio::new(0.5));
+    /// ```
+    pub fn to_hsv(&self) -> Hsv {
+        let (max, min) = self.extrema();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        Hsv::new(
+            self.hue(),
+            Ratio::new(saturation),
+            Ratio::new(max),
+            self.alpha,
+        )
+    }
+
+    /// Returns the largest and smallest of the red, green and blue channels.
+    fn extrema(&self) -> (f64, f64) {
+        let (r, g, b) = (self.r.ratio, self.g.ratio, self.b.ratio);
+
+        (r.max(g).max(b), r.min(g).min(b))
+    }
+
+    /// Returns the hue shared by the HSL and HSV representations of the color.
+    fn hue(&self) -> Angle {
+        let (r, g, b) = (self.r.ratio, self.g.ratio, self.b.ratio);
+        let (max, min) = self.extrema();
+        let delta = max - min;
+
+        let sector = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        Angle::new((sector * 60.0).to_radians())
+    }
+
+    /// Returns `true` if all color channels lie within the sRGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Rgb};
+    ///
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(1.2),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert!(!rgb.in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&c.ratio))
+    }
+
+    /// Naively clips every channel (including alpha) into the range `[0, 1]`.
+    ///
+    /// Clipping channels independently can shift the hue of out-of-gamut colors.
+    /// Use [`Rgb::gamut_clamp`] to preserve the hue instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Rgb};
+    ///
+    /// let rgb = Rgb::new(
+    ///     Ratio::new(1.2),
+    ///     Ratio::new(-0.1),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let clipped = rgb.clip();
+    /// assert_eq!(clipped.r, Ratio::new(1.0));
+    /// assert_eq!(clipped.g, Ratio::new(0.0));
+    /// assert_eq!(clipped.b, Ratio::new(0.5));
+    /// ```
+    pub fn clip(&self) -> Rgb {
+        Rgb::new(
+            Ratio::new(self.r.ratio.clamp(0.0, 1.0)),
+            Ratio::new(self.g.ratio.clamp(0.0, 1.0)),
+            Ratio::new(self.b.ratio.clamp(0.0, 1.0)),
+            Ratio::new(self.alpha.ratio.clamp(0.0, 1.0)),
+        )
+    }
+
+    /// Maps the color into the sRGB gamut by reducing its chroma in the OKLCH
+    /// color space while keeping lightness and hue.
+    ///
+    /// Colors already inside the gamut are returned unchanged (apart from alpha
+    /// being clipped into `[0, 1]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Oklch, Ratio};
+    ///
+    /// // A very saturated green that lies outside of sRGB.
+    /// let oklch = Oklch::new(
+    ///     Ratio::new(0.8),
+    ///     Ratio::new(0.4),
+    ///     Angle::new(2.5),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let rgb = oklch.to_oklab().to_linear_rgb().to_rgb();
+    /// assert!(!rgb.in_gamut());
+    ///
+    /// let clamped = rgb.gamut_clamp();
+    /// assert!(clamped.in_gamut());
+    /// ```
+    pub fn gamut_clamp(&self) -> Rgb {
+        if self.in_gamut() {
+            return self.clip();
+        }
+
+        let oklch = self.to_linear_rgb().to_oklab().to_oklch();
+        let lightness = oklch.lightness.ratio.clamp(0.0, 1.0);
+
+        let to_rgb = |chroma: f64| {
+            Oklch::new(
+                Ratio::new(lightness),
+                Ratio::new(chroma),
+                oklch.hue.clone(),
+                oklch.alpha,
+            )
+            .to_oklab()
+            .to_linear_rgb()
+            .to_rgb()
+        };
+
+        // Binary search for the largest chroma that still fits into the gamut.
+        let mut low = 0.0;
+        let mut high = oklch.chroma.ratio;
+        for _ in 0..32 {
+            let mid = (low + high) / 2.0;
+            if to_rgb(mid).in_gamut() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        to_rgb(low).clip()
+    }
+}
+
+/// A color in the CMYK color space.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "CmykCbor")]
+pub struct Cmyk {
+    /// The cyan component of the color.
+    pub cyan: Ratio,
+    /// The magenta component of the color.
+    pub magenta: Ratio,
+    /// The yellow component of the color.
+    pub yellow: Ratio,
+    /// The key (black) component of the color.
+    pub key: Ratio,
+}
+
+impl Cmyk {
+    /// Creates a new Cmyk color with the given cyan, magenta, yellow, and key (black) components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Cmyk, Ratio};
+    ///
+    /// let c = Cmyk::new(
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert_eq!(c.key, Ratio::new(1.0));
+    /// ```
+    pub const fn new(cyan: Ratio, magenta: Ratio, yellow: Ratio, key: Ratio) -> Self {
+        Self {
+            cyan,
+            magenta,
+            yellow,
+            key,
+        }
+    }
+
+    /// Converts the color to the standard RGB color space.
+    ///
+    /// CMYK colors carry no alpha, so the result is fully opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Cmyk, Ratio};
+    ///
+    /// let c = Cmyk::new(
+    ///     Ratio::new(0.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(0.0),
+    /// );
+    /// let rgb = c.to_rgb();
+    /// assert_eq!(rgb.r, Ratio::new(1.0));
+    /// assert_eq!(rgb.g, Ratio::new(0.0));
+    /// ```
+    pub const fn to_rgb(&self) -> Rgb {
+        let white = 1.0 - self.key.ratio;
+
+        Rgb::new(
+            Ratio::new((1.0 - self.cyan.ratio) * white),
+            Ratio::new((1.0 - self.magenta.ratio) * white),
+            Ratio::new((1.0 - self.yellow.ratio) * white),
+            Ratio::new(1.0),
+        )
+    }
+}
+
+/// A color in the HSL (Hue, Saturation, Lightness) color space.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "HslCbor")]
+pub struct Hsl {
+    /// The hue component of the color.
+    pub hue: Angle,
+    /// The saturation component of the color.
+    pub saturation: Ratio,
+    /// The lightness component of the color.
+    pub lightness: Ratio,
+    /// The alpha (transparency) component of the color.
+    pub alpha: Ratio,
+}
+
+impl Hsl {
+    /// Creates a new Hsl color with the given hue, saturation, lightness, and alpha components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Hsl, Ratio};
+    ///
+    /// let h = Hsl::new(
+    ///     Angle::new(0.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert_eq!(h.saturation, Ratio::new(1.0));
+    /// ```
+    pub const fn new(hue: Angle, saturation: Ratio, lightness: Ratio, alpha: Ratio) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+            alpha,
+        }
+    }
+
+    /// Converts the color to the standard RGB color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Hsl, Ratio};
+    ///
+    /// let h = Hsl::new(
+    ///     Angle::new(0.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let rgb = h.to_rgb();
+    /// assert_eq!(rgb.r, Ratio::new(1.0));
+    /// assert_eq!(rgb.b, Ratio::new(0.0));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb {
+        let hue = self.hue.normalized().deg();
+        let (saturation, lightness) = (self.saturation.ratio, self.lightness.ratio);
+        let a = saturation * lightness.min(1.0 - lightness);
+
+        let channel = |n: f64| {
+            let k = (n + hue / 30.0) % 12.0;
+            Ratio::new(lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0))
+        };
+
+        Rgb::new(channel(0.0), channel(8.0), channel(4.0), self.alpha)
+    }
+}
+
+/// A color in the HSV (Hue, Saturation, Value) color space.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "HsvCbor")]
+pub struct Hsv {
+    /// The hue component of the color.
+    pub hue: Angle,
+    /// The saturation component of the color.
+    pub saturation: Ratio,
+    /// The value (brightness) component of the color.
+    pub value: Ratio,
+    /// The alpha (transparency) component of the color.
+    pub alpha: Ratio,
+}
+
+impl Hsv {
+    /// Creates a new Hsv color with the given hue, saturation, value, and alpha components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Hsv, Ratio};
+    ///
+    /// let h = Hsv::new(
+    ///     Angle::new(0.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// assert_eq!(h.value, Ratio::new(1.0));
+    /// ```
+    pub const fn new(hue: Angle, saturation: Ratio, value: Ratio, alpha: Ratio) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+            alpha,
+        }
+    }
+
+    /// Converts the color to the standard RGB color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Hsv, Ratio};
+    ///
+    /// let h = Hsv::new(
+    ///     Angle::new(0.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    ///     Ratio::new(1.0),
+    /// );
+    /// let rgb = h.to_rgb();
+    /// assert_eq!(rgb.r, Ratio::new(1.0));
+    /// assert_eq!(rgb.g, Ratio::new(0.0));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb {
+        let hue = self.hue.normalized().deg();
+        let (saturation, value) = (self.saturation.ratio, self.value.ratio);
+
+        let channel = |n: f64| {
+            let k = (n + hue / 60.0) % 6.0;
+            Ratio::new(value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0))
+        };
+
+        Rgb::new(channel(5.0), channel(3.0), channel(1.0), self.alpha)
+    }
+}
+
+/// Represents a color in various color spaces.
+///
+/// Besides the tagged map of each color space, a color also decodes from a hex
+/// string such as `"#ff4136"`, see [`Color::from_hex`]. It is always encoded as
+/// a tagged map.
+///
+/// Decoding rejects components outside of the ranges checked by
+/// [`Validate`](crate::Validate), while hues may be any finite angle. Colors
+/// converted with [`Color::to_space`] may lie outside of the target gamut, so
+/// bring them into range before sending them to Typst, e.g. with
+/// [`Rgb::gamut_clamp`].
+///
+/// The `Debug` output is compact, e.g. `red` for a predefined color or
+/// `rgb(1.0, 0.5, 0.0, a=1.0)` with hues in degrees. The alternate form `{:#?}`
+/// prints the full structure instead.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(with = "ColorCbor"))]
+#[serde(tag = "typwire-type", try_from = "Value")]
+pub enum Color {
+    /// A grayscale color with lightness and alpha (transparency).
+    #[serde(rename = "color-luma")]
+    Luma(Luma),
+    /// A color in the OKLab color space.
+    #[serde(rename = "color-oklab")]
+    Oklab(Oklab),
+    /// A color in the OKLCH color space.
+    #[serde(rename = "color-oklch")]
+    Oklch(Oklch),
+    /// A color in the linear RGB color space.
+    #[serde(rename = "color-linear-rgb")]
+    LinearRgb(LinearRgb),
+    /// A color in the standard RGB color space.
+    #[serde(rename = "color-rgb")]
+    Rgb(Rgb),
+    /// A color in the CMYK color space.
+    #[serde(rename = "color-cmyk")]
+    Cmyk(Cmyk),
+    /// A color in the HSL (Hue, Saturation, Lightness) color space.
+    #[serde(rename = "color-hsl")]
+    Hsl(Hsl),
+    /// A color in the HSV (Hue, Saturation, Value) color space.
+    #[serde(rename = "color-hsv")]
+    Hsv(Hsv),
+}
+
+impl Color {
+    /// Looks up one of the predefined colors by its (case-insensitive) name.
+    ///
+    /// The names match Typst's predefined colors, e.g. `"red"` or `"eastern"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, color};
+    ///
+    /// assert_eq!(Color::from_name("Navy"), Some(color::NAVY));
+    /// assert_eq!(Color::from_name("mauve"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Color> {
+        PALETTE_NAMES
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))
+            .map(|index| PALETTE[index].clone())
+    }
+
+    /// Parses an RGB color from a hex string.
+    ///
+    /// Accepts the forms `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`; the leading
+    /// `#` is optional.
+    ///
+    /// # Errors
+    /// Returns a string error message if the string is not a valid hex color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, Ratio, Rgb};
+    ///
+    /// let color = Color::from_hex("#ff000080").unwrap();
+    /// assert_eq!(
+    ///     color,
+    ///     Color::Rgb(Rgb::new(
+    ///         Ratio::new(1.0),
+    ///         Ratio::new(0.0),
+    ///         Ratio::new(0.0),
+    ///         Ratio::from_u8(128),
+    ///     ))
+    /// );
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Color, String> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || format!("Invalid hex color: {hex}");
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let channel = |index: usize, width: usize| {
+            let value = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16)
+                .map_err(|_| invalid())?;
+            // Short forms repeat each digit, e.g. `f` means `ff`.
+            Ok::<_, String>(Ratio::from_u8(if width == 1 { value * 17 } else { value }))
+        };
+
+        let (count, width) = match digits.len() {
+            3 => (3, 1),
+            4 => (4, 1),
+            6 => (3, 2),
+            8 => (4, 2),
+            _ => return Err(invalid()),
+        };
+        let alpha = if count == 4 {
+            channel(3, width)?
+        } else {
+            Ratio::new(1.0)
+        };
+
+        Ok(Color::Rgb(Rgb::new(
+            channel(0, width)?,
+            channel(1, width)?,
+            channel(2, width)?,
+            alpha,
+        )))
+    }
+
+    /// Parses a color the way a user would write it.
+    ///
+    /// Tries, in order, a predefined color name (see [`Color::from_name`]), a hex
+    /// color (see [`Color::from_hex`]) and an `rgb(...)` or `oklch(...)` color
+    /// function. RGB channels are given as `0`–`255` or percentages, OKLCH
+    /// lightness as `0`–`1` or a percentage, chroma as a number or a percentage
+    /// of `0.4` and hue in degrees or with an angle unit. An optional alpha may
+    /// follow as a fourth component, or after a `/` in whitespace-separated form.
+    ///
+    /// # Errors
+    /// Returns a string error message listing the attempted formats if none matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, color};
+    ///
+    /// assert_eq!(Color::parse("navy").unwrap(), color::NAVY);
+    /// assert_eq!(Color::parse("rgb(255, 65, 54)").unwrap(), color::RED);
+    /// assert!(Color::parse("oklch(70% 0.1 120deg / 50%)").is_ok());
+    /// ```
+    pub fn parse(s: &str) -> Result<Color, String> {
+        let s = s.trim();
+        if let Some(color) = Color::from_name(s) {
+            return Ok(color);
+        }
+
+        let hex = match Color::from_hex(s) {
+            Ok(color) => return Ok(color),
+            Err(err) => err,
+        };
+        let function = match crate::css::parse_color_function(s) {
+            Ok(color) => return Ok(color),
+            Err(err) => err,
+        };
+
+        Err(format!(
+            "Invalid color: {s} (not a predefined color name; {hex}; {function})"
+        ))
+    }
+
+    /// Creates a color of the given space from its raw components.
+    ///
+    /// The components are given in the order of the color's fields, with ratios
+    /// as plain numbers and hues in degrees. Luma takes 2 components and all
+    /// other spaces take 4.
+    ///
+    /// # Errors
+    /// Returns a string error message if the number of components does not
+    /// match the space or any component is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, ColorSpace, color};
+    ///
+    /// let color = Color::try_from_components(ColorSpace::Luma, &[0.0, 1.0]).unwrap();
+    /// assert_eq!(color, color::BLACK);
+    /// assert!(Color::try_from_components(ColorSpace::Rgb, &[1.0, 0.0]).is_err());
+    /// ```
+    pub fn try_from_components(space: ColorSpace, components: &[f64]) -> Result<Color, String> {
+        let expected = if space == ColorSpace::Luma { 2 } else { 4 };
+        if components.len() != expected {
+            return Err(format!(
+                "Expected {expected} components for {space:?} color, got {}",
+                components.len()
+            ));
+        }
+
+        let ratio = |index: usize| Ratio::new(components[index]);
+        let hue = |index: usize| Angle::new(components[index].to_radians());
+
+        let color = match space {
+            ColorSpace::Luma => Color::Luma(Luma::new(ratio(0), ratio(1))),
+            ColorSpace::Oklab => Color::Oklab(Oklab::new(ratio(0), ratio(1), ratio(2), ratio(3))),
+            ColorSpace::Oklch => Color::Oklch(Oklch::new(ratio(0), ratio(1), hue(2), ratio(3))),
+            ColorSpace::LinearRgb => {
+                Color::LinearRgb(LinearRgb::new(ratio(0), ratio(1), ratio(2), ratio(3)))
+            }
+            ColorSpace::Rgb => Color::Rgb(Rgb::new(ratio(0), ratio(1), ratio(2), ratio(3))),
+            ColorSpace::Cmyk => Color::Cmyk(Cmyk::new(ratio(0), ratio(1), ratio(2), ratio(3))),
+            ColorSpace::Hsl => Color::Hsl(Hsl::new(hue(0), ratio(1), ratio(2), ratio(3))),
+            ColorSpace::Hsv => Color::Hsv(Hsv::new(hue(0), ratio(1), ratio(2), ratio(3))),
+        };
+        color.validate().map_err(|problems| problems.join(", "))?;
+
+        Ok(color)
+    }
+
+    /// Returns the raw components of the color, the inverse of
+    /// [`Color::try_from_components`].
+    ///
+    /// Ratios are returned as plain numbers and hues in degrees, ordered per variant:
+    ///
+    /// * `Luma`: `[lightness, alpha]`
+    /// * `Oklab`: `[lightness, a, b, alpha]`
+    /// * `Oklch`: `[lightness, chroma, hue, alpha]`
+    /// * `LinearRgb` and `Rgb`: `[r, g, b, alpha]`
+    /// * `Cmyk`: `[cyan, magenta, yellow, key]`
+    /// * `Hsl`: `[hue, saturation, lightness, alpha]`
+    /// * `Hsv`: `[hue, saturation, value, alpha]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::color;
+    ///
+    /// assert_eq!(color::BLACK.components(), vec![0.0, 1.0]);
+    /// ```
+    pub fn components(&self) -> Vec<f64> {
+        match self {
+            Color::Luma(luma) => vec![luma.lightness.ratio, luma.alpha.ratio],
+            Color::Oklab(oklab) => vec![
+                oklab.lightness.ratio,
+                oklab.a.ratio,
+                oklab.b.ratio,
+                oklab.alpha.ratio,
+            ],
+            Color::Oklch(oklch) => vec![
+                oklch.lightness.ratio,
+                oklch.chroma.ratio,
+                oklch.hue.deg(),
+                oklch.alpha.ratio,
+            ],
+            Color::LinearRgb(LinearRgb { r, g, b, alpha }) | Color::Rgb(Rgb { r, g, b, alpha }) => {
+                vec![r.ratio, g.ratio, b.ratio, alpha.ratio]
+            }
+            Color::Cmyk(cmyk) => vec![
+                cmyk.cyan.ratio,
+                cmyk.magenta.ratio,
+                cmyk.yellow.ratio,
+                cmyk.key.ratio,
+            ],
+            Color::Hsl(hsl) => vec![
+                hsl.hue.deg(),
+                hsl.saturation.ratio,
+                hsl.lightness.ratio,
+                hsl.alpha.ratio,
+            ],
+            Color::Hsv(hsv) => vec![
+                hsv.hue.deg(),
+                hsv.saturation.ratio,
+                hsv.value.ratio,
+                hsv.alpha.ratio,
+            ],
+        }
+    }
+
+    /// Encodes the color into deterministic CBOR bytes.
+    ///
+    /// The color is encoded like [`ToBytes::to_bytes`](crate::ToBytes::to_bytes)
+    /// does, but with map keys in canonical order and a unique representation
+    /// for every float, so equal colors always yield identical bytes. This makes
+    /// the encoding suitable as a cache or content-addressing key.
+    ///
+    /// # Panics
+    ///
+    /// With the `fixed` feature, panics if a component is not finite, as it has
+    /// no fixed-point representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, FromBytes as _, color};
+    ///
+    /// let bytes = color::RED.encode_canonical();
+    #[cfg_attr(
+        not(feature = "fixed"),
+        doc = " assert_eq!(Color::from_bytes(&bytes).unwrap(), color::RED);"
+    )]
+    #[cfg_attr(
+        feature = "fixed",
+        doc = " assert_eq!(Color::from_bytes(&bytes).unwrap().encode_canonical(), bytes);"
+    )]
+    /// ```
+    pub fn encode_canonical(&self) -> Vec<u8> {
+        let value = Value::serialized(self).expect("colors can always be represented as values");
+
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&crate::canonicalize(value), &mut bytes)
+            .expect("writing into a vector cannot fail");
+
+        bytes
+    }
+
+    /// Returns the color space of the color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{ColorSpace, color};
+    ///
+    /// assert_eq!(color::BLACK.space(), ColorSpace::Luma);
+    /// assert_eq!(color::RED.space(), ColorSpace::Rgb);
+    /// ```
+    pub const fn space(&self) -> ColorSpace {
+        match self {
+            Color::Luma(_) => ColorSpace::Luma,
+            Color::Oklab(_) => ColorSpace::Oklab,
+            Color::Oklch(_) => ColorSpace::Oklch,
+            Color::LinearRgb(_) => ColorSpace::LinearRgb,
+            Color::Rgb(_) => ColorSpace::Rgb,
+            Color::Cmyk(_) => ColorSpace::Cmyk,
+            Color::Hsl(_) => ColorSpace::Hsl,
+            Color::Hsv(_) => ColorSpace::Hsv,
+        }
+    }
+
+    /// Converts the color into the given color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{ColorSpace, color};
+    ///
+    /// let converted = color::RED.to_space(ColorSpace::Oklch);
+    /// assert_eq!(converted.space(), ColorSpace::Oklch);
+    /// ```
+    pub fn to_space(&self, space: ColorSpace) -> Color {
+        if self.space() == space {
+            return self.clone();
+        }
+
+        match space {
+            ColorSpace::Luma => Color::Luma(self.to_luma()),
+            ColorSpace::Oklab => Color::Oklab(self.to_oklab()),
+            ColorSpace::Oklch => Color::Oklch(self.to_oklch()),
+            ColorSpace::LinearRgb => Color::LinearRgb(self.to_linear_rgb()),
+            ColorSpace::Rgb => Color::Rgb(self.to_rgb()),
+            ColorSpace::Cmyk => Color::Cmyk(self.to_cmyk()),
+            ColorSpace::Hsl => Color::Hsl(self.to_hsl()),
+            ColorSpace::Hsv => Color::Hsv(self.to_hsv()),
+        }
+    }
+
+    /// Converts the color to the standard RGB color space.
+    ///
+    /// The result may lie outside of the RGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let rgb = color::WHITE.to_rgb();
+    /// assert_eq!(rgb.r, Ratio::new(1.0));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb {
+        match self {
+            Color::Luma(luma) => luma.to_rgb(),
+            Color::Rgb(rgb) => rgb.clone(),
+            Color::Cmyk(cmyk) => cmyk.to_rgb(),
+            Color::Hsl(hsl) => hsl.to_rgb(),
+            Color::Hsv(hsv) => hsv.to_rgb(),
+            other => other.to_linear_rgb().to_rgb(),
+        }
+    }
+
+    /// Converts the color to a grayscale color, using its luminance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let luma = color::WHITE.to_luma();
+    /// assert_eq!(luma.lightness, Ratio::new(1.0));
+    /// ```
+    pub fn to_luma(&self) -> Luma {
+        match self {
+            Color::Luma(luma) => luma.clone(),
+            other => other.to_rgb().to_luma(),
+        }
+    }
+
+    /// Converts the color to the CMYK color space.
+    ///
+    /// CMYK colors carry no alpha, so transparency is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let cmyk = color::BLACK.to_cmyk();
+    /// assert_eq!(cmyk.key, Ratio::new(1.0));
+    /// ```
+    pub fn to_cmyk(&self) -> Cmyk {
+        match self {
+            Color::Cmyk(cmyk) => cmyk.clone(),
+            other => other.to_rgb().to_cmyk(),
+        }
+    }
+
+    /// Converts the color to the HSL color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let hsl = color::WHITE.to_hsl();
+    /// assert_eq!(hsl.lightness, Ratio::new(1.0));
+    /// ```
+    pub fn to_hsl(&self) -> Hsl {
+        match self {
+            Color::Hsl(hsl) => hsl.clone(),
+            other => other.to_rgb().to_hsl(),
+        }
+    }
+
+    /// Converts the color to the HSV color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let hsv = color::WHITE.to_hsv();
+    /// assert_eq!(hsv.value, Ratio::new(1.0));
+    /// ```
+    pub fn to_hsv(&self) -> Hsv {
+        match self {
+            Color::Hsv(hsv) => hsv.clone(),
+            other => other.to_rgb().to_hsv(),
+        }
+    }
+
+    /// Converts the color to the linear RGB color space.
+    fn to_linear_rgb(&self) -> LinearRgb {
+        match self {
+            Color::Oklab(oklab) => oklab.to_linear_rgb(),
+            Color::Oklch(oklch) => oklch.to_oklab().to_linear_rgb(),
+            Color::LinearRgb(linear_rgb) => linear_rgb.clone(),
+            other => other.to_rgb().to_linear_rgb(),
+        }
+    }
+
+    /// Converts the color to the OKLab color space.
+    fn to_oklab(&self) -> Oklab {
+        match self {
+            Color::Oklab(oklab) => oklab.clone(),
+            Color::Oklch(oklch) => oklch.to_oklab(),
+            other => other.to_linear_rgb().to_oklab(),
+        }
+    }
+
+    /// Returns the Euclidean distance between two colors in the OKLab color space.
+    fn oklab_distance(&self, other: &Color) -> f64 {
+        let (a, b) = (self.to_oklab(), other.to_oklab());
+
+        (a.lightness.ratio - b.lightness.ratio)
+            .hypot(a.a.ratio - b.a.ratio)
+            .hypot(a.b.ratio - b.b.ratio)
+    }
+
+    /// Mixes the color with another one, interpolating in the given color space.
+    ///
+    /// A `t` of `0` yields this color and a `t` of `1` yields `other`, both
+    /// converted into `space`. Hues are interpolated along the shorter arc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, ColorSpace, Luma, Ratio, color};
+    ///
+    /// let gray = color::BLACK.mix(&color::WHITE, Ratio::new(0.5), ColorSpace::Luma);
+    /// assert_eq!(gray, Color::Luma(Luma::new(Ratio::new(0.5), Ratio::new(1.0))));
+    /// ```
+    pub fn mix(&self, other: &Color, t: Ratio, space: ColorSpace) -> Color {
+        let t = t.ratio;
+
+        match (self.to_space(space), other.to_space(space)) {
+            (Color::Luma(a), Color::Luma(b)) => Color::Luma(Luma::new(
+                lerp(a.lightness, b.lightness, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::Oklab(a), Color::Oklab(b)) => Color::Oklab(Oklab::new(
+                lerp(a.lightness, b.lightness, t),
+                lerp(a.a, b.a, t),
+                lerp(a.b, b.b, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::Oklch(a), Color::Oklch(b)) => Color::Oklch(Oklch::new(
+                lerp(a.lightness, b.lightness, t),
+                lerp(a.chroma, b.chroma, t),
+                lerp_hue((&a.hue, a.chroma), (&b.hue, b.chroma), t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::LinearRgb(a), Color::LinearRgb(b)) => Color::LinearRgb(LinearRgb::new(
+                lerp(a.r, b.r, t),
+                lerp(a.g, b.g, t),
+                lerp(a.b, b.b, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::Rgb(a), Color::Rgb(b)) => Color::Rgb(Rgb::new(
+                lerp(a.r, b.r, t),
+                lerp(a.g, b.g, t),
+                lerp(a.b, b.b, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::Cmyk(a), Color::Cmyk(b)) => Color::Cmyk(Cmyk::new(
+                lerp(a.cyan, b.cyan, t),
+                lerp(a.magenta, b.magenta, t),
+                lerp(a.yellow, b.yellow, t),
+                lerp(a.key, b.key, t),
+            )),
+            (Color::Hsl(a), Color::Hsl(b)) => Color::Hsl(Hsl::new(
+                lerp_hue((&a.hue, a.saturation), (&b.hue, b.saturation), t),
+                lerp(a.saturation, b.saturation, t),
+                lerp(a.lightness, b.lightness, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            (Color::Hsv(a), Color::Hsv(b)) => Color::Hsv(Hsv::new(
+                lerp_hue((&a.hue, a.saturation), (&b.hue, b.saturation), t),
+                lerp(a.saturation, b.saturation, t),
+                lerp(a.value, b.value, t),
+                lerp(a.alpha, b.alpha, t),
+            )),
+            _ => unreachable!("both colors were converted into the same space"),
+        }
+    }
+
+    /// Mixes like [`Color::mix`], but with the color channels premultiplied by
+    /// alpha, as browsers render transparent gradients.
+    ///
+    /// This keeps a transparent color from tinting its opaque neighbor. Spaces
+    /// with a hue and CMYK, which has no alpha, are mixed without premultiplying.
+    pub(crate) fn mix_premultiplied(&self, other: &Color, t: Ratio, space: ColorSpace) -> Color {
+        let (a, b) = (self.to_space(space), other.to_space(space));
+        let (a_alpha, b_alpha) = (a.alpha(), b.alpha());
+        let alpha = lerp(a_alpha, b_alpha, t.ratio);
+
+        // Equal alphas cancel out, and without any opacity there is nothing to weight.
+        if a_alpha == b_alpha || alpha.ratio <= 0.0 {
+            return a.mix(&b, t, space);
+        }
+
+        let channel = |x: Ratio, y: Ratio| {
+            let premultiplied = lerp(
+                Ratio::new(x.ratio * a_alpha.ratio),
+                Ratio::new(y.ratio * b_alpha.ratio),
+                t.ratio,
+            );
+            Ratio::new(premultiplied.ratio / alpha.ratio)
+        };
+
+        match (a, b) {
+            (Color::Luma(x), Color::Luma(y)) => {
+                Color::Luma(Luma::new(channel(x.lightness, y.lightness), alpha))
+            }
+            (Color::Oklab(x), Color::Oklab(y)) => Color::Oklab(Oklab::new(
+                channel(x.lightness, y.lightness),
+                channel(x.a, y.a),
+                channel(x.b, y.b),
+                alpha,
+            )),
+            (Color::LinearRgb(x), Color::LinearRgb(y)) => Color::LinearRgb(LinearRgb::new(
+                channel(x.r, y.r),
+                channel(x.g, y.g),
+                channel(x.b, y.b),
+                alpha,
+            )),
+            (Color::Rgb(x), Color::Rgb(y)) => Color::Rgb(Rgb::new(
+                channel(x.r, y.r),
+                channel(x.g, y.g),
+                channel(x.b, y.b),
+                alpha,
+            )),
+            (a, b) => a.mix(&b, t, space),
+        }
+    }
+
+    /// Converts the color to the OKLCH color space.
+    ///
+    /// Achromatic colors report a chroma of `0` and a hue of `0°`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, Ratio, color};
+    ///
+    /// let oklch = color::WHITE.to_oklch();
+    /// assert!((oklch.lightness.ratio - 1.0).abs() < 1e-6);
+    /// assert_eq!(oklch.chroma, Ratio::new(0.0));
+    /// assert_eq!(oklch.hue, Angle::new(0.0));
+    /// ```
+    pub fn to_oklch(&self) -> Oklch {
+        match self {
+            Color::Oklch(oklch) => oklch.clone(),
+            other => other.to_oklab().to_oklch(),
+        }
+    }
+
+    /// Rotates the hue of the color in the OKLCH color space.
+    ///
+    /// The result is converted back into the color's original color space and,
+    /// unless that is OKLab or OKLCH, mapped into the sRGB gamut by reducing its
+    /// chroma.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, ColorSpace, color};
+    ///
+    /// let rotated = color::RED.rotate_hue(Angle::new(std::f64::consts::PI));
+    /// assert_eq!(rotated.space(), ColorSpace::Rgb);
+    /// ```
+    pub fn rotate_hue(&self, by: Angle) -> Color {
+        self.map_oklch(|oklch| {
+            oklch.hue = Angle::new(oklch.hue.rad() + by.rad()).normalized();
+        })
+    }
+
+    /// Increases the chroma of the color in the OKLCH color space.
+    ///
+    /// The chroma is clamped into `[0, 0.4]`, the range covered by Typst's OKLCH
+    /// colors. The result is converted back into the color's original color space,
+    /// where it is mapped into the sRGB gamut like [`Color::rotate_hue`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let vivid = color::TEAL.saturate(Ratio::new(0.05));
+    /// assert!(vivid.chroma().ratio > color::TEAL.chroma().ratio);
+    /// ```
+    pub fn saturate(&self, amount: Ratio) -> Color {
+        self.map_oklch(|oklch| {
+            oklch.chroma = Ratio::new((oklch.chroma.ratio + amount.ratio).clamp(0.0, MAX_CHROMA));
+        })
+    }
+
+    /// Decreases the chroma of the color in the OKLCH color space.
+    ///
+    /// The chroma is clamped into `[0, 0.4]`, so desaturating by a large amount
+    /// yields a gray. The result is converted back into the color's original
+    /// color space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let gray = color::RED.desaturate(Ratio::new(1.0));
+    /// assert!(gray.chroma().ratio < 1e-6);
+    /// ```
+    pub fn desaturate(&self, amount: Ratio) -> Color {
+        self.saturate(Ratio::new(-amount.ratio))
+    }
+
+    /// Sets the perceptual lightness of the color in the OKLCH color space.
+    ///
+    /// The lightness is clamped into `[0, 1]`. The result is converted back into
+    /// the color's original color space and mapped into its gamut, see
+    /// [`Color::rotate_hue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let light = color::NAVY.with_lightness(Ratio::new(0.8));
+    /// assert!((light.lightness().ratio - 0.8).abs() < 1e-6);
+    /// ```
+    pub fn with_lightness(&self, lightness: Ratio) -> Color {
+        self.map_oklch(|oklch| {
+            oklch.lightness = Ratio::new(lightness.ratio.clamp(0.0, 1.0));
+        })
+    }
+
+    /// Sets the chroma of the color in the OKLCH color space.
+    ///
+    /// The chroma is clamped into `[0, 0.4]`. Achromatic colors have a hue of
+    /// `0°` (see [`Color::to_oklch`]), so giving them chroma tints them towards
+    /// that hue. The result is converted back into the color's original color
+    /// space and mapped into its gamut (see [`Color::rotate_hue`]), which for
+    /// [`Color::Luma`] discards the chroma again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let muted = color::RED.with_chroma(Ratio::new(0.05));
+    /// assert!((muted.chroma().ratio - 0.05).abs() < 1e-6);
+    /// ```
+    pub fn with_chroma(&self, chroma: Ratio) -> Color {
+        self.map_oklch(|oklch| {
+            oklch.chroma = Ratio::new(chroma.ratio.clamp(0.0, MAX_CHROMA));
+        })
+    }
+
+    /// Sets the hue of the color in the OKLCH color space.
+    ///
+    /// The hue is normalized into `[0°, 360°)`. Achromatic colors have no
+    /// visible hue, so they are returned unchanged (up to conversion rounding).
+    /// The result is converted back into the color's original color space and
+    /// mapped into its gamut, see [`Color::rotate_hue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, color};
+    ///
+    /// let shifted = color::RED.with_hue(Angle::new(3.0));
+    /// assert!((shifted.hue().rad() - 3.0).abs() < 1e-6);
+    /// ```
+    pub fn with_hue(&self, hue: Angle) -> Color {
+        self.map_oklch(|oklch| {
+            oklch.hue = hue.normalized();
+        })
+    }
+
+    /// Edits the OKLCH representation of the color and converts the result back
+    /// into the color's original color space.
+    ///
+    /// Spaces bounded by the sRGB gamut get the result mapped into the gamut
+    /// with [`Rgb::gamut_clamp`], which keeps its lightness and hue.
+    fn map_oklch(&self, f: impl FnOnce(&mut Oklch)) -> Color {
+        let mut oklch = self.to_oklch();
+        f(&mut oklch);
+
+        match self.space() {
+            space @ (ColorSpace::Oklab | ColorSpace::Oklch) => Color::Oklch(oklch).to_space(space),
+            space => Color::Rgb(Color::Oklch(oklch).to_rgb().gamut_clamp()).to_space(space),
+        }
+    }
+
+    /// Returns the perceptual lightness of the color (OKLCH lightness).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::color;
+    ///
+    /// assert!(color::BLACK.lightness().ratio.abs() < 1e-6);
+    /// ```
+    pub fn lightness(&self) -> Ratio {
+        self.to_oklch().lightness
+    }
+
+    /// Returns the chroma of the color (OKLCH chroma).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::color;
+    ///
+    /// assert!(color::RED.chroma().ratio > color::GRAY.chroma().ratio);
+    /// ```
+    pub fn chroma(&self) -> Ratio {
+        self.to_oklch().chroma
+    }
+
+    /// Returns the hue of the color (OKLCH hue).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Angle, color};
+    ///
+    /// assert_eq!(color::GRAY.hue(), Angle::new(0.0));
+    /// ```
+    pub fn hue(&self) -> Angle {
+        self.to_oklch().hue
+    }
+
+    /// Returns the alpha (opacity) of the color.
+    ///
+    /// CMYK colors have no alpha channel and are always opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// assert_eq!(color::RED.alpha(), Ratio::new(1.0));
+    /// ```
+    pub fn alpha(&self) -> Ratio {
+        match self {
+            Color::Luma(Luma { alpha, .. })
+            | Color::Oklab(Oklab { alpha, .. })
+            | Color::Oklch(Oklch { alpha, .. })
+            | Color::LinearRgb(LinearRgb { alpha, .. })
+            | Color::Rgb(Rgb { alpha, .. })
+            | Color::Hsl(Hsl { alpha, .. })
+            | Color::Hsv(Hsv { alpha, .. }) => *alpha,
+            Color::Cmyk(_) => Ratio::new(1.0),
+        }
+    }
+
+    /// Sets the alpha (opacity) of the color.
+    ///
+    /// The alpha is clamped into `[0, 1]`. CMYK colors have no alpha channel,
+    /// so they are converted to RGB unless the color stays opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let faded = color::RED.with_alpha(Ratio::new(0.25));
+    /// assert_eq!(faded.alpha(), Ratio::new(0.25));
+    /// ```
+    pub fn with_alpha(&self, alpha: Ratio) -> Color {
+        let alpha = Ratio::new(alpha.ratio.clamp(0.0, 1.0));
+        let mut color = match self {
+            Color::Cmyk(_) if alpha.ratio < 1.0 => Color::Rgb(self.to_rgb()),
+            color => color.clone(),
+        };
+
+        match &mut color {
+            Color::Luma(Luma { alpha: current, .. })
+            | Color::Oklab(Oklab { alpha: current, .. })
+            | Color::Oklch(Oklch { alpha: current, .. })
+            | Color::LinearRgb(LinearRgb { alpha: current, .. })
+            | Color::Rgb(Rgb { alpha: current, .. })
+            | Color::Hsl(Hsl { alpha: current, .. })
+            | Color::Hsv(Hsv { alpha: current, .. }) => *current = alpha,
+            Color::Cmyk(_) => {}
+        }
+
+        color
+    }
+
+    /// Multiplies the alpha (opacity) of the color by `factor`.
+    ///
+    /// The resulting alpha is clamped into `[0, 1]`. CMYK colors are converted to
+    /// RGB when they become transparent, see [`Color::with_alpha`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, color};
+    ///
+    /// let faded = color::RED.scale_alpha(Ratio::new(0.5)).scale_alpha(Ratio::new(0.5));
+    /// assert_eq!(faded.alpha(), Ratio::new(0.25));
+    /// ```
+    pub fn scale_alpha(&self, factor: Ratio) -> Color {
+        self.with_alpha(Ratio::new(self.alpha().ratio * factor.ratio))
+    }
+
+    /// Clamps every component into its valid range and normalizes hues.
+    ///
+    /// Components are clamped into `[0, 1]`, except for the OKLab `a` and `b`
+    /// components, which are clamped into `[-0.4, 0.4]`, and the OKLCH chroma,
+    /// which is clamped into `[0, 0.4]`. Hues are normalized into `[0°, 360°)`.
+    /// The result always passes [`Validate`](crate::Validate) unless a
+    /// component is NaN or infinite. Colors that were out of range may look
+    /// noticeably different afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Color, Ratio, Rgb};
+    ///
+    /// let color = Color::Rgb(Rgb::new(
+    ///     Ratio::new(1.2),
+    ///     Ratio::new(-0.1),
+    ///     Ratio::new(0.5),
+    ///     Ratio::new(1.0),
+    /// ));
+    /// assert_eq!(color.sanitize().components(), vec![1.0, 0.0, 0.5, 1.0]);
+    /// ```
+    pub fn sanitize(&self) -> Color {
+        let unit = |ratio: &mut Ratio| ratio.ratio = ratio.ratio.clamp(0.0, 1.0);
+        let chroma = |ratio: &mut Ratio| ratio.ratio = ratio.ratio.clamp(-MAX_CHROMA, MAX_CHROMA);
+        let hue = |angle: &mut Angle| *angle = angle.normalized();
+
+        let mut color = self.clone();
+        match &mut color {
+            Color::Luma(luma) => {
+                unit(&mut luma.lightness);
+                unit(&mut luma.alpha);
+            }
+            Color::Oklab(oklab) => {
+                unit(&mut oklab.lightness);
+                chroma(&mut oklab.a);
+                chroma(&mut oklab.b);
+                unit(&mut oklab.alpha);
+            }
+            Color::Oklch(oklch) => {
+                unit(&mut oklch.lightness);
+                oklch.chroma.ratio = oklch.chroma.ratio.clamp(0.0, MAX_CHROMA);
+                hue(&mut oklch.hue);
+                unit(&mut oklch.alpha);
+            }
+            Color::LinearRgb(LinearRgb { r, g, b, alpha }) | Color::Rgb(Rgb { r, g, b, alpha }) => {
+                [r, g, b, alpha].into_iter().for_each(unit);
+            }
+            Color::Cmyk(Cmyk {
+                cyan,
+                magenta,
+                yellow,
+                key,
+            }) => {
+                [cyan, magenta, yellow, key].into_iter().for_each(unit);
+            }
+            Color::Hsl(Hsl {
+                hue: h,
+                saturation,
+                lightness,
+                alpha,
+            }) => {
+                hue(h);
+                [saturation, lightness, alpha].into_iter().for_each(unit);
+            }
+            Color::Hsv(Hsv {
+                hue: h,
+                saturation,
+                value,
+                alpha,
+            }) => {
+                hue(h);
+                [saturation, value, alpha].into_iter().for_each(unit);
+            }
+        }
+
+        color
+    }
+
+    /// Blends `other` onto this color using a blend mode.
+    ///
+    /// This color is the backdrop and `other` the source layered on top. Both
+    /// are converted to sRGB and clipped to its gamut before blending each
+    /// channel. The result is an RGB color with the backdrop's alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{BlendMode, color};
+    ///
+    /// let blended = color::RED.blend(&color::WHITE, BlendMode::Multiply);
+    /// assert_eq!(blended.to_rgb(), color::RED.to_rgb());
+    /// ```
+    pub fn blend(&self, other: &Color, mode: BlendMode) -> Color {
+        let (backdrop, source) = (self.to_rgb(), other.to_rgb());
+        let channel = |b: Ratio, s: Ratio| {
+            Ratio::new(mode.apply(b.ratio.clamp(0.0, 1.0), s.ratio.clamp(0.0, 1.0)))
+        };
+
+        Color::Rgb(Rgb::new(
+            channel(backdrop.r, source.r),
+            channel(backdrop.g, source.g),
+            channel(backdrop.b, source.b),
+            backdrop.alpha,
+        ))
+    }
+
+    /// Returns the WCAG 2 relative luminance of the color, from `0` to `1`.
+    ///
+    /// Out-of-gamut channels are clipped and alpha is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::color;
+    ///
+    /// assert_eq!(color::WHITE.relative_luminance(), 1.0);
+    /// assert_eq!(color::BLACK.relative_luminance(), 0.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        let rgb = self.to_rgb();
+        let channel = |value: Ratio| srgb_decode(value.ratio.clamp(0.0, 1.0));
+
+        0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+    }
+
+    /// Returns the WCAG 2 contrast ratio between two colors, from `1` to `21`.
+    ///
+    /// The ratio is symmetric, so the order of the colors does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::color;
+    ///
+    /// assert_eq!(color::BLACK.contrast_ratio(&color::WHITE), 21.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Checks whether text in this color on `background` meets a WCAG 2
+    /// conformance level.
+    ///
+    /// See [`WcagLevel::min_contrast`] for the required contrast ratios.
+    ///
+    /// # Arguments
+    ///
+    /// * `background` - The color behind the text.
+    /// * `level` - The conformance level to check.
+    /// * `large_text` - Whether the text is large (at least 18pt, or 14pt bold).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{WcagLevel, color};
+    ///
+    /// assert!(color::NAVY.meets_wcag(&color::WHITE, WcagLevel::AAA, false));
+    /// assert!(!color::GRAY.meets_wcag(&color::WHITE, WcagLevel::AA, true));
+    /// ```
+    pub fn meets_wcag(&self, background: &Color, level: WcagLevel, large_text: bool) -> bool {
+        self.contrast_ratio(background) >= level.min_contrast(large_text)
+    }
+}
+
+/// A separable blend mode combining two colors channel by channel, see [`Color::blend`].
+///
+/// In the formulas below, `b` is the backdrop channel and `s` the source channel.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlendMode {
+    /// Darkens by multiplying the channels: `b * s`.
+    Multiply,
+    /// Lightens by multiplying the inverted channels: `b + s - b * s`.
+    Screen,
+    /// Multiplies dark and screens light backdrop channels, boosting contrast.
+    Overlay,
+    /// Keeps the darker channel: `min(b, s)`.
+    Darken,
+    /// Keeps the lighter channel: `max(b, s)`.
+    Lighten,
+}
+
+impl BlendMode {
+    /// Blends a single backdrop and source channel in `[0, 1]`.
+    fn apply(self, backdrop: f64, source: f64) -> f64 {
+        let screen = |b: f64, s: f64| b + s - b * s;
+
+        match self {
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => screen(backdrop, source),
+            BlendMode::Overlay if backdrop <= 0.5 => 2.0 * backdrop * source,
+            BlendMode::Overlay => screen(2.0 * backdrop - 1.0, source),
+            BlendMode::Darken => backdrop.min(source),
+            BlendMode::Lighten => backdrop.max(source),
+        }
+    }
+}
+
+/// A WCAG 2 conformance level for text contrast, see [`Color::meets_wcag`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WcagLevel {
+    /// The minimum level, requiring a contrast of 4.5:1, or 3:1 for large text.
+    AA,
+    /// The enhanced level, requiring a contrast of 7:1, or 4.5:1 for large text.
+    AAA,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio the level requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::WcagLevel;
+    ///
+    /// assert_eq!(WcagLevel::AA.min_contrast(false), 4.5);
+    /// assert_eq!(WcagLevel::AAA.min_contrast(true), 4.5);
+    /// ```
+    pub const fn min_contrast(self, large_text: bool) -> f64 {
+        match (self, large_text) {
+            (WcagLevel::AA, false) => 4.5,
+            (WcagLevel::AA, true) => 3.0,
+            (WcagLevel::AAA, false) => 7.0,
+            (WcagLevel::AAA, true) => 4.5,
+        }
+    }
+}
+
+// Decodes a color struct through an unchecked shim and rejects components
+// outside of their range. Hues may be any finite angle.
+macro_rules! checked_color {
+    ($name:ident, $cbor:ident, $tag:literal { $($(#[$attr:meta])* $field:ident: $ty:ty),* $(,)? }) => {
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        struct $cbor {
+            $($(#[$attr])* $field: $ty,)*
+        }
+
+        impl TryFrom<$cbor> for $name {
+            type Error = String;
+
+            fn try_from(cbor: $cbor) -> Result<Self, Self::Error> {
+                let color = $name { $($field: cbor.$field,)* };
+                Color::from(color.clone())
+                    .validate()
+                    .map_err(|problems| format!("Invalid {}: {}", $tag, problems.join(", ")))?;
+                Ok(color)
+            }
+        }
+    };
+}
+
+checked_color!(Luma, LumaCbor, "color-luma" {
+    lightness: Ratio,
+    alpha: Ratio,
+});
+
+checked_color!(Oklab, OklabCbor, "color-oklab" {
+    lightness: Ratio,
+    a: Ratio,
+    b: Ratio,
+    alpha: Ratio,
+});
+
+checked_color!(Oklch, OklchCbor, "color-oklch" {
+    lightness: Ratio,
+    chroma: Ratio,
+    #[serde(deserialize_with = "crate::angle::deserialize_angle_or_degrees")]
+    hue: Angle,
+    alpha: Ratio,
+});
+
+checked_color!(LinearRgb, LinearRgbCbor, "color-linear-rgb" {
+    r: Ratio,
+    g: Ratio,
+    b: Ratio,
+    alpha: Ratio,
+});
+
+checked_color!(Rgb, RgbCbor, "color-rgb" {
+    r: Ratio,
+    g: Ratio,
+    b: Ratio,
+    alpha: Ratio,
+});
+
+checked_color!(Cmyk, CmykCbor, "color-cmyk" {
+    cyan: Ratio,
+    magenta: Ratio,
+    yellow: Ratio,
+    key: Ratio,
+});
+
+checked_color!(Hsl, HslCbor, "color-hsl" {
+    #[serde(deserialize_with = "crate::angle::deserialize_angle_or_degrees")]
+    hue: Angle,
+    saturation: Ratio,
+    lightness: Ratio,
+    alpha: Ratio,
+});
+
+checked_color!(Hsv, HsvCbor, "color-hsv" {
+    #[serde(deserialize_with = "crate::angle::deserialize_angle_or_degrees")]
+    hue: Angle,
+    saturation: Ratio,
+    value: Ratio,
+    alpha: Ratio,
+});
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "typwire-type")]
+enum ColorCbor {
+    #[serde(rename = "color-luma")]
+    Luma(Luma),
+    #[serde(rename = "color-oklab")]
+    Oklab(Oklab),
+    #[serde(rename = "color-oklch")]
+    Oklch(Oklch),
+    #[serde(rename = "color-linear-rgb")]
+    LinearRgb(LinearRgb),
+    #[serde(rename = "color-rgb")]
+    Rgb(Rgb),
+    #[serde(rename = "color-cmyk")]
+    Cmyk(Cmyk),
+    #[serde(rename = "color-hsl")]
+    Hsl(Hsl),
+    #[serde(rename = "color-hsv")]
+    Hsv(Hsv),
+}
+
+// Deserialize from a hex string or from ColorCbor, accepting the legacy type key
+impl TryFrom<Value> for Color {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::Text(hex) = &value {
+            return Color::from_hex(hex);
+        }
+
+        let cbor: ColorCbor = crate::normalize_type_key(value)
+            .deserialized()
+            .map_err(crate::value_error_message)?;
+
+        Ok(match cbor {
+            ColorCbor::Luma(luma) => Color::Luma(luma),
+            ColorCbor::Oklab(oklab) => Color::Oklab(oklab),
+            ColorCbor::Oklch(oklch) => Color::Oklch(oklch),
+            ColorCbor::LinearRgb(linear_rgb) => Color::LinearRgb(linear_rgb),
+            ColorCbor::Rgb(rgb) => Color::Rgb(rgb),
+            ColorCbor::Cmyk(cmyk) => Color::Cmyk(cmyk),
+            ColorCbor::Hsl(hsl) => Color::Hsl(hsl),
+            ColorCbor::Hsv(hsv) => Color::Hsv(hsv),
+        })
+    }
+}
+
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return match self {
+                Color::Luma(luma) => f.debug_tuple("Luma").field(luma).finish(),
+                Color::Oklab(oklab) => f.debug_tuple("Oklab").field(oklab).finish(),
+                Color::Oklch(oklch) => f.debug_tuple("Oklch").field(oklch).finish(),
+                Color::LinearRgb(linear_rgb) => {
+                    f.debug_tuple("LinearRgb").field(linear_rgb).finish()
+                }
+                Color::Rgb(rgb) => f.debug_tuple("Rgb").field(rgb).finish(),
+                Color::Cmyk(cmyk) => f.debug_tuple("Cmyk").field(cmyk).finish(),
+                Color::Hsl(hsl) => f.debug_tuple("Hsl").field(hsl).finish(),
+                Color::Hsv(hsv) => f.debug_tuple("Hsv").field(hsv).finish(),
+            };
+        }
+
+        if let Some(index) = PALETTE.iter().position(|color| color == self) {
+            return f.write_str(PALETTE_NAMES[index]);
+        }
+
+        // Hues are the components given in degrees, see `Color::components`.
+        let hue = match self.space() {
+            ColorSpace::Oklch => Some(2),
+            ColorSpace::Hsl | ColorSpace::Hsv => Some(0),
+            _ => None,
+        };
+        let alpha = match self.space() {
+            ColorSpace::Cmyk => None,
+            _ => Some(self.components().len() - 1),
+        };
+
+        write!(f, "{}(", self.space().name())?;
+        for (index, component) in self.components().into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            if Some(index) == alpha {
+                f.write_str("a=")?;
+            }
+            write!(f, "{component:?}")?;
+            if Some(index) == hue {
+                f.write_str("deg")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+// Conversions from structs to Color enum
+impl From<Luma> for Color {
+    fn from(luma: Luma) -> Self {
+        Color::Luma(luma)
+    }
+}
+
+impl From<Oklab> for Color {
     fn from(oklab: Oklab) -> Self {
         Color::Oklab(oklab)
     }
@@ -460,8 +2345,140 @@ impl From<Hsv> for Color {
     }
 }
 
+impl Validate for Color {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        let unit = || 0.0..=1.0;
+        let chroma = || -MAX_CHROMA..=MAX_CHROMA;
+
+        match self {
+            Color::Luma(luma) => {
+                problems.range("lightness", luma.lightness.ratio, unit());
+                problems.range("alpha", luma.alpha.ratio, unit());
+            }
+            Color::Oklab(oklab) => {
+                problems.range("lightness", oklab.lightness.ratio, unit());
+                problems.range("a", oklab.a.ratio, chroma());
+                problems.range("b", oklab.b.ratio, chroma());
+                problems.range("alpha", oklab.alpha.ratio, unit());
+            }
+            Color::Oklch(oklch) => {
+                problems.range("lightness", oklch.lightness.ratio, unit());
+                problems.range("chroma", oklch.chroma.ratio, 0.0..=MAX_CHROMA);
+                problems.nested("hue", &oklch.hue);
+                problems.range("alpha", oklch.alpha.ratio, unit());
+            }
+            Color::LinearRgb(LinearRgb { r, g, b, alpha }) | Color::Rgb(Rgb { r, g, b, alpha }) => {
+                problems.range("r", r.ratio, unit());
+                problems.range("g", g.ratio, unit());
+                problems.range("b", b.ratio, unit());
+                problems.range("alpha", alpha.ratio, unit());
+            }
+            Color::Cmyk(cmyk) => {
+                problems.range("cyan", cmyk.cyan.ratio, unit());
+                problems.range("magenta", cmyk.magenta.ratio, unit());
+                problems.range("yellow", cmyk.yellow.ratio, unit());
+                problems.range("key", cmyk.key.ratio, unit());
+            }
+            Color::Hsl(hsl) => {
+                problems.nested("hue", &hsl.hue);
+                problems.range("saturation", hsl.saturation.ratio, unit());
+                problems.range("lightness", hsl.lightness.ratio, unit());
+                problems.range("alpha", hsl.alpha.ratio, unit());
+            }
+            Color::Hsv(hsv) => {
+                problems.nested("hue", &hsv.hue);
+                problems.range("saturation", hsv.saturation.ratio, unit());
+                problems.range("value", hsv.value.ratio, unit());
+                problems.range("alpha", hsv.alpha.ratio, unit());
+            }
+        }
+
+        problems.finish()
+    }
+}
+
+impl Validate for ColorGradient {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        match self {
+            ColorGradient::Color(color) => color.validate(),
+            ColorGradient::Gradient(gradient) => gradient.validate(),
+        }
+    }
+}
+
+/// Returns the palette entry that is perceptually closest to `color`.
+///
+/// Closeness is measured as the Euclidean distance in the OKLab color space.
+/// Ties resolve to the earliest palette entry. If the palette is empty, the
+/// color is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Color, color};
+///
+/// let orange = Color::from_hex("#ff8000").unwrap();
+/// let palette = [color::BLACK, color::RED, color::YELLOW, color::BLUE];
+/// assert_eq!(color::quantize_to_palette(&orange, &palette), color::RED);
+/// ```
+pub fn quantize_to_palette(color: &Color, palette: &[Color]) -> Color {
+    palette
+        .iter()
+        .min_by(|a, b| color.oklab_distance(a).total_cmp(&color.oklab_distance(b)))
+        .unwrap_or(color)
+        .clone()
+}
+
+/// Linearly interpolates between two ratios.
+fn lerp(a: Ratio, b: Ratio, t: f64) -> Ratio {
+    Ratio::new(a.ratio + (b.ratio - a.ratio) * t)
+}
+
+/// Interpolates between two hues along the shorter arc.
+///
+/// Each hue is paired with the chroma (or saturation) of its color. The hue of
+/// an achromatic color is meaningless, so the other hue is used in its place.
+fn lerp_hue(a: (&Angle, Ratio), b: (&Angle, Ratio), t: f64) -> Angle {
+    let (from, to) = match (a.1.ratio == 0.0, b.1.ratio == 0.0) {
+        (true, false) => (b.0.rad(), b.0.rad()),
+        (false, true) => (a.0.rad(), a.0.rad()),
+        _ => (a.0.rad(), b.0.rad()),
+    };
+
+    let delta =
+        (to - from + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI;
+
+    Angle::new(from + delta * t).normalized()
+}
+
+/// Applies the sRGB transfer function to a linear channel value.
+fn srgb_encode(value: f64) -> f64 {
+    let abs = value.abs();
+    let encoded = if abs <= 0.0031308 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+
+    encoded.copysign(value)
+}
+
+/// Reverts the sRGB transfer function, yielding a linear channel value.
+fn srgb_decode(value: f64) -> f64 {
+    let abs = value.abs();
+    let decoded = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+
+    decoded.copysign(value)
+}
+
 /// Represents either a single color or a gradient.
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum ColorGradient {
     /// A single color.
@@ -469,3 +2486,642 @@ pub enum ColorGradient {
     /// A gradient of colors.
     Gradient(Gradient),
 }
+
+impl ColorGradient {
+    /// Returns the color space used when blending this paint.
+    ///
+    /// Gradients report their own interpolation space, while plain colors fall
+    /// back to [`ColorSpace::Oklab`], the default space for gradients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{ColorGradient, ColorSpace, color};
+    ///
+    /// let paint = ColorGradient::Color(color::RED);
+    /// assert_eq!(paint.effective_space(), ColorSpace::Oklab);
+    /// ```
+    pub const fn effective_space(&self) -> ColorSpace {
+        match self {
+            ColorGradient::Color(_) => ColorSpace::Oklab,
+            ColorGradient::Gradient(gradient) => gradient.space(),
+        }
+    }
+
+    /// Flattens the paint into sRGB colors for a rendering backend.
+    ///
+    /// Gradient stops keep their order and are paired with their offsets. Only
+    /// the colors are converted, so the gradient's geometry and interpolation
+    /// space are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{ColorGradient, PaintKind, color};
+    ///
+    /// let paint = ColorGradient::Color(color::RED);
+    /// assert_eq!(paint.resolve(), PaintKind::Solid(color::RED.to_rgb()));
+    /// ```
+    pub fn resolve(&self) -> PaintKind {
+        match self {
+            ColorGradient::Color(color) => PaintKind::Solid(color.to_rgb()),
+            ColorGradient::Gradient(gradient) => PaintKind::Gradient(
+                gradient
+                    .stops()
+                    .iter()
+                    .map(|stop| (stop.offset.ratio, stop.color.to_rgb()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// A paint flattened into sRGB colors, see [`ColorGradient::resolve`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum PaintKind {
+    /// A single color.
+    Solid(Rgb),
+    /// The offsets and colors of a gradient's stops.
+    Gradient(Vec<(f64, Rgb)>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromBytes as _, Stop, ToBytes as _, assert_decoded_color};
+
+    fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{RED:?}"), "red");
+
+        let rgb = Color::Rgb(Rgb::new(
+            Ratio::new(1.0),
+            Ratio::new(0.5),
+            Ratio::new(0.0),
+            Ratio::new(1.0),
+        ));
+        assert_eq!(format!("{rgb:?}"), "rgb(1.0, 0.5, 0.0, a=1.0)");
+
+        let hsv = Color::Hsv(Hsv::new(
+            Angle::new(std::f64::consts::PI),
+            Ratio::new(0.5),
+            Ratio::new(0.25),
+            Ratio::new(0.5),
+        ));
+        assert_eq!(format!("{hsv:?}"), "hsv(180.0deg, 0.5, 0.25, a=0.5)");
+
+        assert!(format!("{rgb:#?}").starts_with("Rgb(\n    Rgb {"));
+    }
+
+    #[test]
+    fn palette() {
+        assert!(PALETTE.contains(&WHITE));
+        assert!(PALETTE.contains(&BLACK));
+    }
+
+    #[test]
+    fn to_oklch_roundtrip() {
+        let colors = [
+            Color::Hsl(Hsl::new(
+                Angle::new(2.0),
+                Ratio::new(0.6),
+                Ratio::new(0.4),
+                Ratio::new(1.0),
+            )),
+            Color::Hsv(Hsv::new(
+                Angle::new(2.0),
+                Ratio::new(0.6),
+                Ratio::new(0.4),
+                Ratio::new(1.0),
+            )),
+            RED,
+        ];
+
+        for color in colors {
+            let rgb = match &color {
+                Color::Hsl(hsl) => hsl.to_rgb(),
+                Color::Hsv(hsv) => hsv.to_rgb(),
+                Color::Rgb(rgb) => rgb.clone(),
+                _ => unreachable!(),
+            };
+            let back = color.to_oklch().to_oklab().to_linear_rgb().to_rgb();
+
+            assert!((rgb.r.ratio - back.r.ratio).abs() < 1e-6);
+            assert!((rgb.g.ratio - back.g.ratio).abs() < 1e-6);
+            assert!((rgb.b.ratio - back.b.ratio).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn rotate_hue_complementary() {
+        let Color::Rgb(rotated) = RED.rotate_hue(Angle::new(std::f64::consts::PI)) else {
+            panic!("expected an RGB color");
+        };
+        let rotated = rotated.clip();
+
+        assert!(rotated.r.ratio < rotated.g.ratio);
+        assert!(rotated.r.ratio < rotated.b.ratio);
+    }
+
+    #[test]
+    fn rotate_hue_identity() {
+        let Color::Rgb(rotated) = RED.rotate_hue(Angle::new(0.0)) else {
+            panic!("expected an RGB color");
+        };
+        let Color::Rgb(red) = RED else {
+            unreachable!();
+        };
+
+        assert!((rotated.r.ratio - red.r.ratio).abs() < 1e-6);
+        assert!((rotated.g.ratio - red.g.ratio).abs() < 1e-6);
+        assert!((rotated.b.ratio - red.b.ratio).abs() < 1e-6);
+    }
+
+    #[test]
+    fn saturate() {
+        let color = Color::Oklch(Oklch::new(
+            Ratio::new(0.6),
+            Ratio::new(0.1),
+            Angle::new(1.0),
+            Ratio::new(1.0),
+        ));
+
+        assert!(color.saturate(Ratio::new(0.05)).chroma().ratio > 0.1);
+        assert!(color.desaturate(Ratio::new(0.05)).chroma().ratio < 0.1);
+    }
+
+    #[test]
+    fn desaturate_rgb() {
+        let desaturated = TEAL.desaturate(Ratio::new(0.05));
+
+        assert_eq!(desaturated.space(), ColorSpace::Rgb);
+        assert!(desaturated.chroma().ratio < TEAL.chroma().ratio);
+    }
+
+    #[test]
+    fn quantize_to_palette() {
+        let gray = Color::Luma(Luma::new(Ratio::new(0.3), Ratio::new(1.0)));
+
+        assert_eq!(super::quantize_to_palette(&gray, &[WHITE, BLACK]), BLACK);
+        assert_eq!(super::quantize_to_palette(&NAVY, &PALETTE), NAVY);
+        assert_eq!(super::quantize_to_palette(&gray, &[]), gray);
+    }
+
+    #[test]
+    fn quantize_to_palette_tie() {
+        let black = Color::Rgb(Rgb::new(
+            Ratio::new(0.0),
+            Ratio::new(0.0),
+            Ratio::new(0.0),
+            Ratio::new(1.0),
+        ));
+
+        let quantized = super::quantize_to_palette(&GRAY, &[black.clone(), BLACK]);
+        assert_eq!(quantized, black);
+        let quantized = super::quantize_to_palette(&GRAY, &[BLACK, black]);
+        assert_eq!(quantized, BLACK);
+    }
+
+    #[test]
+    fn with_alpha() {
+        let faded = RED.with_alpha(Ratio::new(1.5));
+        assert_eq!(faded.alpha(), Ratio::new(1.0));
+
+        let cmyk = Color::Cmyk(Cmyk::new(
+            Ratio::new(0.0),
+            Ratio::new(1.0),
+            Ratio::new(1.0),
+            Ratio::new(0.0),
+        ));
+        assert_eq!(cmyk.with_alpha(Ratio::new(1.0)), cmyk);
+
+        let faded = cmyk.with_alpha(Ratio::new(0.5));
+        assert_eq!(faded.space(), ColorSpace::Rgb);
+        assert_eq!(faded.alpha(), Ratio::new(0.5));
+    }
+
+    #[test]
+    fn scale_alpha() {
+        let faded = BLUE.with_alpha(Ratio::new(0.5));
+        assert_eq!(faded.scale_alpha(Ratio::new(0.5)).alpha(), Ratio::new(0.25));
+        assert_eq!(faded.scale_alpha(Ratio::new(4.0)).alpha(), Ratio::new(1.0));
+        assert_eq!(faded.scale_alpha(Ratio::new(-1.0)).alpha(), Ratio::new(0.0));
+    }
+
+    #[test]
+    fn sanitize() {
+        let oklch = Color::Oklch(Oklch::new(
+            Ratio::new(1.5),
+            Ratio::new(0.6),
+            Angle::new(-std::f64::consts::FRAC_PI_2),
+            Ratio::new(-1.0),
+        ));
+        let sanitized = oklch.sanitize();
+        assert_eq!(
+            sanitized,
+            Color::Oklch(Oklch::new(
+                Ratio::new(1.0),
+                Ratio::new(0.4),
+                Angle::new(1.5 * std::f64::consts::PI),
+                Ratio::new(0.0),
+            ))
+        );
+        assert!(sanitized.validate().is_ok());
+
+        let oklab = Color::Oklab(Oklab::new(
+            Ratio::new(0.5),
+            Ratio::new(-0.5),
+            Ratio::new(0.1),
+            Ratio::new(1.0),
+        ));
+        assert_eq!(oklab.sanitize().components(), vec![0.5, -0.4, 0.1, 1.0]);
+        assert_eq!(RED.sanitize(), RED);
+    }
+
+    #[test]
+    fn wcag() {
+        assert!((BLACK.contrast_ratio(&WHITE) - 21.0).abs() < 1e-9);
+        assert!(BLACK.meets_wcag(&WHITE, WcagLevel::AAA, false));
+        assert!(WHITE.meets_wcag(&BLACK, WcagLevel::AAA, false));
+
+        assert!(!SILVER.meets_wcag(&WHITE, WcagLevel::AA, false));
+        assert!(!SILVER.meets_wcag(&WHITE, WcagLevel::AA, true));
+
+        // Red on white only passes for large text.
+        assert!(RED.meets_wcag(&WHITE, WcagLevel::AA, true));
+        assert!(!RED.meets_wcag(&WHITE, WcagLevel::AA, false));
+    }
+
+    #[test]
+    fn decode_checks_range() {
+        let rgb = |r: f64| {
+            Color::Rgb(Rgb::new(
+                Ratio::new(r),
+                Ratio::new(0.5),
+                Ratio::new(0.0),
+                Ratio::new(1.0),
+            ))
+        };
+
+        let valid = rgb(1.0);
+        assert_eq!(Color::from_bytes(&encode(&valid)).unwrap(), valid);
+
+        let error = Rgb::from_bytes(&encode(&rgb(2.0))).unwrap_err();
+        assert_eq!(error, "Invalid color-rgb: r: 2 is outside of [0, 1]");
+
+        let error = Color::from_bytes(&encode(&rgb(2.0))).unwrap_err();
+        assert_eq!(error, "Invalid color-rgb: r: 2 is outside of [0, 1]");
+
+        let error = Vec::<Color>::from_bytes(&encode(&vec![rgb(1.0), rgb(2.0)])).unwrap_err();
+        assert_eq!(error, "Invalid color-rgb: r: 2 is outside of [0, 1]");
+    }
+
+    #[test]
+    fn rgb_from_bytes_u8_encoded() {
+        use ciborium::cbor;
+
+        let value = cbor!({
+            "r" => 0,
+            "g" => 0.25,
+            "b" => Ratio::new(0.5),
+            "alpha" => 255,
+        })
+        .unwrap();
+        let rgb = Rgb::from_bytes_u8_encoded(&encode(&value)).unwrap();
+        assert_eq!(
+            rgb,
+            Rgb::new(
+                Ratio::new(0.0),
+                Ratio::new(0.25),
+                Ratio::new(0.5),
+                Ratio::new(1.0)
+            )
+        );
+
+        let value = cbor!({ "r" => 256, "g" => 0, "b" => 0, "alpha" => 255 }).unwrap();
+        let error = Rgb::from_bytes_u8_encoded(&encode(&value)).unwrap_err();
+        assert_eq!(error, "r: 256 is not a byte");
+
+        let error = Rgb::from_bytes_u8_encoded(&encode(&1)).unwrap_err();
+        assert_eq!(error, "Expected a map of Rgb channels");
+    }
+
+    #[test]
+    fn decode_accepts_any_hue() {
+        let hsv = Color::Hsv(Hsv::new(
+            Angle::new(20.0),
+            Ratio::new(0.5),
+            Ratio::new(0.5),
+            Ratio::new(1.0),
+        ));
+        assert_eq!(Color::from_bytes(&encode(&hsv)).unwrap(), hsv);
+    }
+
+    #[test]
+    fn try_from_components_rgb() {
+        let color = Color::try_from_components(ColorSpace::Rgb, &[1.0, 0.5, 0.0, 1.0]).unwrap();
+        assert_eq!(
+            color,
+            Color::Rgb(Rgb::new(
+                Ratio::new(1.0),
+                Ratio::new(0.5),
+                Ratio::new(0.0),
+                Ratio::new(1.0),
+            ))
+        );
+
+        let error = Color::try_from_components(ColorSpace::Rgb, &[1.5, 0.5, 0.0, 1.0]).unwrap_err();
+        assert_eq!(error, "r: 1.5 is outside of [0, 1]");
+    }
+
+    #[test]
+    fn try_from_components_luma() {
+        let color = Color::try_from_components(ColorSpace::Luma, &[0.25, 0.5]).unwrap();
+        assert_eq!(
+            color,
+            Color::Luma(Luma::new(Ratio::new(0.25), Ratio::new(0.5)))
+        );
+    }
+
+    #[test]
+    fn try_from_components_wrong_count() {
+        let error = Color::try_from_components(ColorSpace::Cmyk, &[0.0, 0.0, 0.0]).unwrap_err();
+        assert_eq!(error, "Expected 4 components for Cmyk color, got 3");
+    }
+
+    #[test]
+    fn with_components() {
+        let color = TEAL
+            .with_lightness(Ratio::new(0.6))
+            .with_chroma(Ratio::new(0.08))
+            .with_hue(Angle::new(1.0));
+
+        assert_eq!(color.space(), ColorSpace::Rgb);
+        let oklch = color.to_oklch();
+        assert!((oklch.lightness.ratio - 0.6).abs() < 1e-6);
+        assert!((oklch.chroma.ratio - 0.08).abs() < 1e-6);
+        assert!((oklch.hue.rad() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn with_hue_achromatic() {
+        let Color::Luma(gray) = GRAY.with_hue(Angle::new(2.0)) else {
+            panic!("expected a luma color");
+        };
+        assert!((gray.lightness.ratio - 170.0 / 255.0).abs() < 1e-6);
+
+        let tinted = Color::Oklch(GRAY.to_oklch()).with_chroma(Ratio::new(0.1));
+        assert_eq!(tinted.hue(), Angle::new(0.0));
+    }
+
+    #[test]
+    fn components() {
+        let rgb = Color::Rgb(Rgb::new(
+            Ratio::new(0.1),
+            Ratio::new(0.2),
+            Ratio::new(0.3),
+            Ratio::new(0.4),
+        ));
+        assert_eq!(rgb.components(), vec![0.1, 0.2, 0.3, 0.4]);
+
+        let hsv = Color::try_from_components(ColorSpace::Hsv, &[90.0, 0.5, 0.5, 1.0]).unwrap();
+        let components = hsv.components();
+        assert!((components[0] - 90.0).abs() < 1e-9);
+        assert_eq!(components[1..], [0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn encode_canonical() {
+        let rgb = |b: f64| {
+            Color::Rgb(Rgb::new(
+                Ratio::new(1.0),
+                Ratio::new(0.5),
+                Ratio::new(b),
+                Ratio::new(1.0),
+            ))
+        };
+        assert_eq!(rgb(0.0).encode_canonical(), rgb(-0.0).encode_canonical());
+
+        let value: Value = ciborium::from_reader(rgb(0.0).encode_canonical().as_slice()).unwrap();
+        let keys: Vec<_> = value
+            .as_map()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.as_text().unwrap())
+            .collect();
+        assert_eq!(keys, ["b", "g", "r", "alpha", "typwire-type"]);
+    }
+
+    #[test]
+    fn roundtrip_out_of_gamut() {
+        let oklch = Color::Oklch(Oklch::new(
+            Ratio::new(0.7),
+            Ratio::new(0.35),
+            Angle::new(2.5),
+            Ratio::new(1.0),
+        ));
+        let rgb = oklch.to_space(ColorSpace::Rgb);
+        assert!(!rgb.to_rgb().in_gamut());
+
+        // Conversions may leave the gamut, which only decoding rejects.
+        let error = Color::from_bytes(&rgb.to_bytes().unwrap()).unwrap_err();
+        assert!(error.starts_with("Invalid color-rgb: r: "), "{error}");
+
+        let clamped = Color::Rgb(rgb.to_rgb().gamut_clamp());
+        let decoded = Color::from_bytes(&clamped.to_bytes().unwrap()).unwrap();
+        assert_decoded_color(&decoded, &clamped);
+    }
+
+    #[test]
+    fn adjustments_stay_in_gamut() {
+        let adjusted = [
+            BLUE.with_lightness(Ratio::new(0.9)),
+            RED.rotate_hue(Angle::new(2.0)),
+            RED.with_hue(Angle::new(2.0)),
+            TEAL.saturate(Ratio::new(0.1)),
+            Color::Hsl(ORANGE.to_hsl()).saturate(Ratio::new(0.2)),
+        ];
+
+        for color in adjusted {
+            assert!(color.validate().is_ok(), "{color:?}");
+            assert!(Color::from_bytes(&color.to_bytes().unwrap()).is_ok());
+        }
+
+        // OKLCH is unbounded, so the chroma is kept.
+        let vivid = Color::Oklch(TEAL.to_oklch()).saturate(Ratio::new(0.1));
+        assert!((vivid.chroma().ratio - TEAL.chroma().ratio - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn struct_conversions() {
+        let hsv = Hsv::new(
+            Angle::new(1.0),
+            Ratio::new(0.5),
+            Ratio::new(0.8),
+            Ratio::new(1.0),
+        );
+        let color = Color::Hsv(hsv.clone());
+
+        assert_eq!(color.to_hsv(), hsv);
+        assert_eq!(color.to_rgb(), hsv.to_rgb());
+        assert_eq!(color.to_hsl(), hsv.to_rgb().to_hsl());
+        assert_eq!(color.to_luma(), hsv.to_rgb().to_luma());
+        assert_eq!(color.to_cmyk(), hsv.to_rgb().to_cmyk());
+    }
+
+    #[test]
+    fn decode_hex_string() {
+        let bytes = "#ff4136".to_bytes().unwrap();
+        assert_eq!(Color::from_bytes(&bytes).unwrap(), RED);
+
+        let colors: Vec<Color> = Vec::from_value(Value::Array(vec![
+            Value::Text("#0074d9".into()),
+            BLUE.to_value().unwrap(),
+        ]))
+        .unwrap();
+        assert_eq!(colors[0], BLUE);
+        assert_decoded_color(&colors[1], &BLUE);
+
+        assert!(Color::from_value(Value::Text("red".into())).is_err());
+    }
+
+    #[test]
+    fn resolve() {
+        let paint = ColorGradient::Color(BLACK);
+        assert_eq!(paint.resolve(), PaintKind::Solid(BLACK.to_rgb()));
+
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(NAVY, Ratio::new(0.0)),
+                Stop::new(WHITE.to_space(ColorSpace::Oklch), Ratio::new(0.75)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklch,
+        );
+        let PaintKind::Gradient(stops) = ColorGradient::Gradient(gradient).resolve() else {
+            panic!("expected a gradient paint");
+        };
+        assert_eq!(stops.len(), 2);
+        assert_eq!(stops[0], (0.0, NAVY.to_rgb()));
+        assert_eq!(stops[1].0, 0.75);
+        assert!((stops[1].1.g.ratio - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn blend() {
+        let color = Color::from_hex("#3d9970").unwrap();
+        assert_eq!(color.blend(&WHITE, BlendMode::Multiply), color);
+        assert_eq!(
+            color.blend(&BLACK, BlendMode::Multiply),
+            Color::Rgb(Rgb::new(
+                Ratio::new(0.0),
+                Ratio::new(0.0),
+                Ratio::new(0.0),
+                Ratio::new(1.0),
+            ))
+        );
+        assert_eq!(color.blend(&BLACK, BlendMode::Screen), color);
+        assert_eq!(color.blend(&color, BlendMode::Darken), color);
+        assert_eq!(BLACK.blend(&color, BlendMode::Lighten), color);
+
+        let gray = Color::Rgb(Rgb::new(
+            Ratio::new(0.5),
+            Ratio::new(0.5),
+            Ratio::new(0.5),
+            Ratio::new(1.0),
+        ));
+        let overlay = |backdrop: f64| {
+            let backdrop = Color::Luma(Luma::new(Ratio::new(backdrop), Ratio::new(1.0)));
+            backdrop.blend(&gray, BlendMode::Overlay).to_rgb().r.ratio
+        };
+        assert_eq!(overlay(0.25), 0.25);
+        assert_eq!(overlay(0.75), 0.75);
+    }
+
+    #[test]
+    fn meets_wcag() {
+        // #767676 on white is the classic borderline AA gray at about 4.54:1.
+        let gray = Color::from_hex("#767676").unwrap();
+        let contrast = gray.contrast_ratio(&WHITE);
+        assert!((contrast - 4.54).abs() < 0.01);
+        assert_eq!(contrast, WHITE.contrast_ratio(&gray));
+
+        assert!(gray.meets_wcag(&WHITE, WcagLevel::AA, false));
+        assert!(gray.meets_wcag(&WHITE, WcagLevel::AAA, true));
+        assert!(!gray.meets_wcag(&WHITE, WcagLevel::AAA, false));
+
+        assert!(BLACK.meets_wcag(&WHITE, WcagLevel::AAA, false));
+        assert!(!WHITE.meets_wcag(&WHITE, WcagLevel::AA, true));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Color::parse(" Eastern ").unwrap(), EASTERN);
+        assert_eq!(Color::parse("#0074d9").unwrap(), BLUE);
+        assert_eq!(Color::parse("rgb(0 116 217)").unwrap(), BLUE);
+        assert_eq!(
+            Color::parse("rgba(100%, 0%, 0%, 0.5)").unwrap(),
+            Color::Rgb(Rgb::new(
+                Ratio::new(1.0),
+                Ratio::new(0.0),
+                Ratio::new(0.0),
+                Ratio::new(0.5),
+            ))
+        );
+
+        let Color::Oklch(oklch) = Color::parse("oklch(0.7 50% 0.5turn)").unwrap() else {
+            panic!("expected an oklch color");
+        };
+        assert_eq!(oklch.lightness, Ratio::new(0.7));
+        assert_eq!(oklch.chroma, Ratio::new(0.2));
+        assert!((oklch.hue.deg() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Color::parse("hsl(0, 100%, 50%)").unwrap_err();
+        assert!(
+            error.starts_with("Invalid color: hsl(0, 100%, 50%) (not a predefined color name;")
+        );
+        assert!(error.ends_with("Invalid color function: hsl(0, 100%, 50%))"));
+    }
+
+    #[test]
+    fn effective_space() {
+        let color = ColorGradient::Color(BLUE);
+        assert_eq!(color.effective_space(), ColorSpace::Oklab);
+
+        let gradient =
+            ColorGradient::Gradient(Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Hsl));
+        assert_eq!(gradient.effective_space(), ColorSpace::Hsl);
+    }
+
+    #[test]
+    fn hue_from_degrees() {
+        let tagged = Color::Hsl(Hsl::new(
+            Angle::new(std::f64::consts::PI),
+            Ratio::new(1.0),
+            Ratio::new(0.5),
+            Ratio::new(1.0),
+        ));
+
+        let Value::Map(mut entries) = Value::serialized(&tagged).unwrap() else {
+            panic!("expected a map");
+        };
+        for (key, value) in entries.iter_mut() {
+            if key.as_text() == Some("hue") {
+                *value = Value::Float(180.0);
+            }
+        }
+
+        let decoded = Color::from_bytes(&encode(&Value::Map(entries))).unwrap();
+        assert_eq!(decoded, tagged);
+        assert_decoded_color(&Color::from_bytes(&encode(&tagged)).unwrap(), &tagged);
+    }
+}
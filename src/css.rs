@@ -0,0 +1,386 @@
+use crate::{Angle, Center, Color, ColorSpace, Gradient, Oklch, Ratio, Rgb, Stop};
+
+/// Parses a CSS `linear-gradient(...)`, `radial-gradient(...)` or `conic-gradient(...)`.
+///
+/// CSS measures angles clockwise from the top, whereas Typst measures them
+/// from the positive x-axis, so angles are rotated by `-90°` on the way in.
+pub(crate) fn parse_gradient(css: &str) -> Result<Gradient, String> {
+    let unsupported = || format!("Unsupported CSS gradient: {css}");
+
+    let (name, args) = parse_function(css.trim()).ok_or_else(unsupported)?;
+    let mut args = split_args(args);
+
+    // The first argument either configures the geometry or is already a stop.
+    let config = match args.first() {
+        Some(first) if is_config(first) => Some(args.remove(0)),
+        _ => None,
+    };
+    let stops = parse_stops(&args)?;
+
+    match name {
+        "linear-gradient" => {
+            let degrees = match config {
+                Some(config) => parse_direction(config).ok_or_else(unsupported)?,
+                None => 180.0,
+            };
+
+            Ok(Gradient::linear(
+                stops,
+                typst_angle(degrees),
+                ColorSpace::Rgb,
+            ))
+        }
+        "radial-gradient" => {
+            let (radius, center) = parse_radial_config(config.unwrap_or_default())?;
+
+            Ok(Gradient::radial(
+                stops,
+                center.clone(),
+                Ratio::new(radius),
+                center,
+                Ratio::new(0.0),
+                ColorSpace::Rgb,
+            ))
+        }
+        "conic-gradient" => {
+            let (degrees, center) = match config {
+                Some(config) => parse_conic_config(config).ok_or_else(unsupported)?,
+                None => (0.0, default_center()),
+            };
+
+            Ok(Gradient::conic(
+                stops,
+                typst_angle(degrees),
+                center,
+                ColorSpace::Rgb,
+            ))
+        }
+        _ => Err(unsupported()),
+    }
+}
+
+/// Parses an `rgb(...)`, `rgba(...)` or `oklch(...)` color function.
+///
+/// Components may be separated by commas or by whitespace, with the alpha
+/// optionally following a `/`.
+pub(crate) fn parse_color_function(css: &str) -> Result<Color, String> {
+    let invalid = || format!("Invalid color function: {css}");
+
+    let (name, args) = parse_function(css.trim()).ok_or_else(invalid)?;
+    let args: Vec<&str> = if args.contains(',') {
+        split_args(args)
+    } else {
+        args.split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|arg| !arg.is_empty())
+            .collect()
+    };
+
+    let alpha = match args.get(3) {
+        Some(alpha) => parse_number_or_percentage(alpha, 1.0).ok_or_else(invalid)?,
+        None => 1.0,
+    };
+    if !(3..=4).contains(&args.len()) {
+        return Err(invalid());
+    }
+
+    match name {
+        "rgb" | "rgba" => {
+            let channel = |index: usize| {
+                parse_number_or_percentage(args[index], 255.0)
+                    .map(Ratio::new)
+                    .ok_or_else(invalid)
+            };
+
+            Ok(Color::Rgb(Rgb::new(
+                channel(0)?,
+                channel(1)?,
+                channel(2)?,
+                Ratio::new(alpha),
+            )))
+        }
+        "oklch" => {
+            let lightness = parse_number_or_percentage(args[0], 1.0).ok_or_else(invalid)?;
+            // As in CSS, a chroma of 100% corresponds to 0.4.
+            let chroma = match parse_percentage(args[1]) {
+                Some(percentage) => percentage * 0.4,
+                None => args[1].parse().map_err(|_| invalid())?,
+            };
+            let hue = parse_angle(args[2])
+                .or_else(|| args[2].parse().ok())
+                .ok_or_else(invalid)?;
+
+            Ok(Color::Oklch(Oklch::new(
+                Ratio::new(lightness),
+                Ratio::new(chroma),
+                Angle::new(hue.to_radians()).normalized(),
+                Ratio::new(alpha),
+            )))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses a percentage, or a plain number relative to `max`, into a fraction.
+fn parse_number_or_percentage(value: &str, max: f64) -> Option<f64> {
+    parse_percentage(value).or_else(|| value.parse::<f64>().ok().map(|number| number / max))
+}
+
+/// Splits `name(args)` into its name and the raw argument string.
+fn parse_function(css: &str) -> Option<(&str, &str)> {
+    let open = css.find('(')?;
+    let args = css.strip_suffix(')')?.get(open + 1..)?;
+
+    Some((css[..open].trim(), args))
+}
+
+/// Splits an argument string at all commas that are not nested in parentheses.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, char) in args.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(args[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(args[start..].trim());
+
+    parts
+}
+
+/// Parses color stops, distributing missing offsets like CSS does.
+fn parse_stops(args: &[&str]) -> Result<Vec<Stop>, String> {
+    if args.len() < 2 {
+        return Err("CSS gradient needs at least two color stops".to_string());
+    }
+
+    let parsed = args
+        .iter()
+        .map(|arg| parse_stop(arg))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut offsets: Vec<Option<f64>> = parsed.iter().map(|(_, offset)| *offset).collect();
+    let last = offsets.len() - 1;
+    offsets[0].get_or_insert(0.0);
+    offsets[last].get_or_insert(1.0);
+
+    // Offsets may not decrease.
+    let mut previous = f64::NEG_INFINITY;
+    for offset in offsets.iter_mut().flatten() {
+        *offset = offset.max(previous);
+        previous = *offset;
+    }
+
+    // Spread stops without an offset evenly between their positioned neighbours.
+    let mut index = 1;
+    while index < last {
+        if offsets[index].is_some() {
+            index += 1;
+            continue;
+        }
+
+        let start = index - 1;
+        let end = (index..=last)
+            .find(|&i| offsets[i].is_some())
+            .unwrap_or(last);
+        let (from, to) = (offsets[start].unwrap_or(0.0), offsets[end].unwrap_or(1.0));
+        for (i, offset) in offsets.iter_mut().enumerate().take(end).skip(index) {
+            *offset = Some(from + (to - from) * (i - start) as f64 / (end - start) as f64);
+        }
+        index = end;
+    }
+
+    Ok(parsed
+        .into_iter()
+        .zip(offsets)
+        .map(|((color, _), offset)| Stop::new(color, Ratio::new(offset.unwrap_or(0.0))))
+        .collect())
+}
+
+/// Parses a single `<color> [<percentage>]` stop.
+///
+/// The color is parsed with [`Color::parse`], so color functions may contain
+/// whitespace themselves. Offsets must lie within `[0%, 100%]`, as Typst
+/// rejects stops outside of the gradient.
+fn parse_stop(stop: &str) -> Result<(Color, Option<f64>), String> {
+    if stop.is_empty() {
+        return Err("Empty CSS color stop".to_string());
+    }
+
+    // A color function ends with `)`, so a trailing token after it is the offset.
+    let (color, offset) = match stop.rsplit_once(char::is_whitespace) {
+        Some((color, offset)) if !stop.ends_with(')') => (color, Some(offset)),
+        _ => (stop, None),
+    };
+
+    let color = Color::parse(color)?;
+    let offset = match offset {
+        Some(offset) => {
+            let ratio = parse_percentage(offset)
+                .ok_or_else(|| format!("Invalid CSS stop offset: {offset}"))?;
+            if !(0.0..=1.0).contains(&ratio) {
+                return Err(format!("CSS stop offset {offset} is outside of [0%, 100%]"));
+            }
+            Some(ratio)
+        }
+        None => None,
+    };
+
+    Ok((color, offset))
+}
+
+/// Returns whether the first argument of a gradient configures its geometry
+/// rather than being a color stop.
+fn is_config(arg: &str) -> bool {
+    let first = arg.split_whitespace().next().unwrap_or_default();
+
+    matches!(
+        first,
+        "to" | "from"
+            | "at"
+            | "circle"
+            | "ellipse"
+            | "closest-side"
+            | "closest-corner"
+            | "farthest-side"
+            | "farthest-corner"
+    ) || parse_angle(first).is_some()
+        || parse_percentage(first).is_some()
+}
+
+/// Parses a percentage such as `25%` into a fraction.
+fn parse_percentage(value: &str) -> Option<f64> {
+    if value == "0" {
+        return Some(0.0);
+    }
+
+    value
+        .strip_suffix('%')?
+        .parse::<f64>()
+        .ok()
+        .map(|p| p / 100.0)
+}
+
+/// Parses a CSS angle into degrees.
+fn parse_angle(value: &str) -> Option<f64> {
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / std::f64::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    units.iter().find_map(|(unit, factor)| {
+        value
+            .strip_suffix(unit)
+            .and_then(|number| number.parse::<f64>().ok())
+            .map(|number| number * factor)
+    })
+}
+
+/// Parses the direction of a linear gradient, either an angle or `to <side>`, into degrees.
+fn parse_direction(value: &str) -> Option<f64> {
+    let Some(sides) = value.strip_prefix("to ") else {
+        return parse_angle(value);
+    };
+
+    let mut sides: Vec<&str> = sides.split_whitespace().collect();
+    sides.sort_unstable();
+
+    // Corners assume a square box.
+    match sides.as_slice() {
+        ["top"] => Some(0.0),
+        ["right", "top"] => Some(45.0),
+        ["right"] => Some(90.0),
+        ["bottom", "right"] => Some(135.0),
+        ["bottom"] => Some(180.0),
+        ["bottom", "left"] => Some(225.0),
+        ["left"] => Some(270.0),
+        ["left", "top"] => Some(315.0),
+        _ => None,
+    }
+}
+
+/// Parses an optional `at <x> <y>` position, defaulting to the center.
+fn parse_position(value: &str) -> Option<Center> {
+    if value.is_empty() {
+        return Some(default_center());
+    }
+
+    let mut tokens = value.strip_prefix("at")?.split_whitespace();
+    let x = parse_percentage(tokens.next()?)?;
+    let y = parse_percentage(tokens.next()?)?;
+
+    tokens
+        .next()
+        .is_none()
+        .then(|| Center::new(Ratio::new(x), Ratio::new(y)))
+}
+
+/// Parses the `[ellipse] [<size>] [at <x> <y>]` configuration of a radial
+/// gradient into its radius and center.
+///
+/// The size is a keyword such as `closest-side` or a percentage, defaulting to
+/// `farthest-corner` as in CSS. Typst scales radial gradients with their box
+/// like a CSS ellipse, so sizes are measured in a unit square and `circle` is
+/// rejected.
+fn parse_radial_config(value: &str) -> Result<(f64, Center), String> {
+    let unsupported = || format!("Unsupported CSS radial gradient shape: {value}");
+
+    let tokens: Vec<&str> = value.split_whitespace().collect();
+    let at = (tokens.iter())
+        .position(|&token| token == "at")
+        .unwrap_or(tokens.len());
+    let center = parse_position(&tokens[at..].join(" ")).ok_or_else(unsupported)?;
+
+    let (x, y) = (center.x.ratio, center.y.ratio);
+    let (near_x, far_x) = (x.min(1.0 - x), x.max(1.0 - x));
+    let (near_y, far_y) = (y.min(1.0 - y), y.max(1.0 - y));
+
+    let mut radius = None;
+    for &token in &tokens[..at] {
+        let size = match token {
+            "ellipse" => continue,
+            "circle" => return Err(format!("{} (only ellipses are supported)", unsupported())),
+            "closest-side" => near_x.min(near_y),
+            "farthest-side" => far_x.max(far_y),
+            "closest-corner" => near_x.hypot(near_y),
+            "farthest-corner" => far_x.hypot(far_y),
+            size => parse_percentage(size).ok_or_else(unsupported)?,
+        };
+        if radius.replace(size).is_some() {
+            return Err(unsupported());
+        }
+    }
+
+    Ok((radius.unwrap_or_else(|| far_x.hypot(far_y)), center))
+}
+
+/// Parses the `[from <angle>] [at <x> <y>]` configuration of a conic gradient.
+fn parse_conic_config(value: &str) -> Option<(f64, Center)> {
+    let Some(rest) = value.strip_prefix("from ") else {
+        return Some((0.0, parse_position(value)?));
+    };
+
+    let rest = rest.trim_start();
+    let (angle, position) = rest.split_once(' ').unwrap_or((rest, ""));
+
+    Some((parse_angle(angle)?, parse_position(position.trim())?))
+}
+
+/// Converts a CSS angle in degrees into a Typst angle.
+fn typst_angle(degrees: f64) -> Angle {
+    Angle::new((degrees - 90.0).to_radians()).normalized()
+}
+
+/// Returns the center of the gradient box.
+fn default_center() -> Center {
+    Center::new(Ratio::new(0.5), Ratio::new(0.5))
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-const TYPE_NAME: &str = "datetime";
+use crate::validate::Problems;
+use crate::{Duration, Tagged, Validate};
 
 /// A struct representing a date and time with optional fields.
 ///
@@ -14,6 +15,7 @@ const TYPE_NAME: &str = "datetime";
 /// assert_eq!(dt.month, Some(12));
 /// ```
 #[derive(Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "DateTimeCbor", into = "DateTimeCbor")]
 pub struct DateTime {
     pub year: Option<i64>,
@@ -45,6 +47,261 @@ impl DateTime {
             second: None,
         }
     }
+
+    /// Creates a date without a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a string error message describing every out-of-range field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// let dt = DateTime::from_ymd(2025, 12, 3).unwrap();
+    /// assert_eq!(dt, DateTime::builder().year(2025).month(12).day(3).build());
+    /// assert!(DateTime::from_ymd(2025, 2, 29).is_err());
+    /// ```
+    pub fn from_ymd(year: i64, month: i64, day: i64) -> Result<DateTime, String> {
+        let datetime = Self::builder().year(year).month(month).day(day).build();
+        datetime.check()?;
+
+        Ok(datetime)
+    }
+
+    /// Creates a datetime with all fields set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a string error message describing every out-of-range field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// let dt = DateTime::from_ymd_hms(2025, 12, 3, 14, 30, 0).unwrap();
+    /// assert_eq!(dt.hour, Some(14));
+    /// assert!(DateTime::from_ymd_hms(2025, 12, 3, 24, 0, 0).is_err());
+    /// ```
+    pub fn from_ymd_hms(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<DateTime, String> {
+        let datetime = Self::builder()
+            .year(year)
+            .month(month)
+            .day(day)
+            .hour(hour)
+            .minute(minute)
+            .second(second)
+            .build();
+        datetime.check()?;
+
+        Ok(datetime)
+    }
+
+    /// Validates the datetime, joining all problems into a single message.
+    fn check(&self) -> Result<(), String> {
+        self.validate().map_err(|problems| problems.join(", "))
+    }
+
+    /// Returns a bitmask of the fields that are set.
+    ///
+    /// Bit 0 stands for the year, bit 1 for the month, bit 2 for the day, bit 3
+    /// for the hour, bit 4 for the minute and bit 5 for the second. A date thus
+    /// yields `0b000111` and a time `0b111000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// let dt = DateTime::builder().year(2025).month(12).day(3).build();
+    /// assert_eq!(dt.present_fields(), 0b000111);
+    /// ```
+    pub fn present_fields(&self) -> u8 {
+        [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ]
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.is_some())
+        .fold(0, |mask, (bit, _)| mask | 1 << bit)
+    }
+
+    /// Returns the ISO-8601 week date as `(iso_year, week)`.
+    ///
+    /// Weeks start on Monday and week 1 is the week containing the first
+    /// Thursday of the year, so the ISO year can differ from the calendar year
+    /// around New Year. Returns `None` if the year, month or day is missing or
+    /// the date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// let dt = DateTime::builder().year(2025).month(12).day(3).build();
+    /// assert_eq!(dt.iso_week(), Some((2025, 49)));
+    /// ```
+    pub fn iso_week(&self) -> Option<(i64, u8)> {
+        let (year, month, day) = (self.year?, self.month?, self.day?);
+        if !(1..=i64::from(DateTime::days_in_month(year, month)?)).contains(&day) {
+            return None;
+        }
+
+        let week = iso_week_of(year, month, day)?;
+        if week < 1 {
+            let previous = year.checked_sub(1)?;
+            Some((previous, iso_week_of(previous, 12, 28)? as u8))
+        } else if week > iso_week_of(year, 12, 28)? {
+            Some((year.checked_add(1)?, 1))
+        } else {
+            Some((year, week as u8))
+        }
+    }
+
+    /// Returns the number of seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// The year, month and day are required, while a missing hour, minute or
+    /// second defaults to zero. This makes datetimes of different precision
+    /// comparable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a string error message naming the first missing date field,
+    /// describing every out-of-range field, or if the year is too far from the
+    /// epoch for the seconds to fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// let dt = DateTime::builder().year(1970).month(1).day(2).hour(1).build();
+    /// assert_eq!(dt.to_epoch_seconds(), Ok(90_000));
+    /// ```
+    pub fn to_epoch_seconds(&self) -> Result<i64, String> {
+        let missing = |field| format!("DateTime is missing the {field}");
+        let year = self.year.ok_or_else(|| missing("year"))?;
+        let month = self.month.ok_or_else(|| missing("month"))?;
+        let day = self.day.ok_or_else(|| missing("day"))?;
+        self.check()?;
+
+        let seconds = self.hour.unwrap_or(0) * 3600
+            + self.minute.unwrap_or(0) * 60
+            + self.second.unwrap_or(0);
+
+        days_from_civil(year, month, day)
+            .and_then(|days| days.checked_mul(86_400)?.checked_add(seconds))
+            .ok_or_else(|| format!("DateTime year {year} is out of range"))
+    }
+
+    /// Returns the time elapsed from `earlier` to this datetime.
+    ///
+    /// Both datetimes are converted with [`DateTime::to_epoch_seconds`], so
+    /// missing time fields default to zero. The duration is negative if `self`
+    /// lies before `earlier`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if either datetime is missing a date field or is invalid,
+    /// or if the datetimes are too far apart for the seconds to fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{DateTime, Duration};
+    ///
+    /// let start = DateTime::builder().year(2025).month(2).day(28).build();
+    /// let end = DateTime::builder().year(2025).month(3).day(1).hour(12).build();
+    /// assert_eq!(end.duration_since(&start), Some(Duration::new(129_600.0)));
+    /// ```
+    pub fn duration_since(&self, earlier: &DateTime) -> Option<Duration> {
+        let (end, start) = (
+            self.to_epoch_seconds().ok()?,
+            earlier.to_epoch_seconds().ok()?,
+        );
+        let seconds = end.checked_sub(start)?;
+
+        Some(Duration::new(seconds as f64))
+    }
+
+    /// Returns whether the given year is a leap year in the proleptic Gregorian
+    /// calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// assert!(DateTime::is_leap_year(2024));
+    /// assert!(!DateTime::is_leap_year(2100));
+    /// ```
+    pub const fn is_leap_year(year: i64) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Returns the number of days in the given month of the given year, or
+    /// `None` if the month is not within `1..=12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::DateTime;
+    ///
+    /// assert_eq!(DateTime::days_in_month(2024, 2), Some(29));
+    /// assert_eq!(DateTime::days_in_month(2025, 13), None);
+    /// ```
+    pub const fn days_in_month(year: i64, month: i64) -> Option<u8> {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+            4 | 6 | 9 | 11 => Some(30),
+            2 if Self::is_leap_year(year) => Some(29),
+            2 => Some(28),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the number of days between 1970-01-01 and the given civil date, or
+/// `None` if it does not fit into an `i64`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> Option<i64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era.checked_mul(146097)?.checked_add(day_of_era - 719468)
+}
+
+/// Returns the raw ISO week of a date relative to its calendar year.
+///
+/// The result is `0` for days belonging to the last week of the previous year
+/// and may exceed the number of weeks in the year for days in week 1 of the next.
+fn iso_week_of(year: i64, month: i64, day: i64) -> Option<i64> {
+    let days = days_from_civil(year, month, day)?;
+    let ordinal = days - days_from_civil(year, 1, 1)? + 1;
+    // 1970-01-01 was a Thursday, ISO weekdays run from Monday (1) to Sunday (7).
+    let weekday = (days.rem_euclid(7) + 3) % 7 + 1;
+
+    Some((ordinal - weekday + 10) / 7)
 }
 
 pub struct DateTimeBuilder {
@@ -183,9 +440,51 @@ impl DateTimeBuilder {
     }
 }
 
+impl Tagged for DateTime {
+    const TYPE_NAME: &'static str = "datetime";
+}
+
+impl Validate for DateTime {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+
+        if let Some(month) = self.month {
+            problems.range("month", month, 1..=12);
+        }
+        if let Some(day) = self.day {
+            let days = self
+                .year
+                .zip(self.month)
+                .and_then(|(year, month)| DateTime::days_in_month(year, month))
+                .map_or(31, i64::from);
+            problems.range("day", day, 1..=days);
+        }
+        if let Some(hour) = self.hour {
+            problems.range("hour", hour, 0..=23);
+        }
+        if let Some(minute) = self.minute {
+            problems.range("minute", minute, 0..=59);
+        }
+        if let Some(second) = self.second {
+            problems.range("second", second, 0..=59);
+        }
+
+        problems.finish()
+    }
+}
+
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct DateTimeCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<DateTime, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<DateTime>")
+    )]
     typwire_type: String,
     year: Option<i64>,
     month: Option<i64>,
@@ -199,7 +498,7 @@ struct DateTimeCbor {
 impl From<DateTime> for DateTimeCbor {
     fn from(value: DateTime) -> Self {
         Self {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: DateTime::TYPE_NAME.to_string(),
             year: value.year,
             month: value.month,
             day: value.day,
@@ -215,13 +514,6 @@ impl TryFrom<DateTimeCbor> for DateTime {
     type Error = String;
 
     fn try_from(value: DateTimeCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for DateTime: {}",
-                value.typwire_type
-            ));
-        }
-
         Ok(Self {
             year: value.year,
             month: value.month,
@@ -232,3 +524,154 @@ impl TryFrom<DateTimeCbor> for DateTime {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i64, month: i64, day: i64) -> DateTime {
+        DateTime::builder().year(year).month(month).day(day).build()
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(DateTime::is_leap_year(2000));
+        assert!(!DateTime::is_leap_year(1900));
+        assert!(DateTime::is_leap_year(-4));
+
+        assert_eq!(DateTime::days_in_month(2000, 2), Some(29));
+        assert_eq!(DateTime::days_in_month(1900, 2), Some(28));
+        assert_eq!(DateTime::days_in_month(2023, 2), Some(28));
+        assert_eq!(DateTime::days_in_month(2023, 0), None);
+    }
+
+    #[test]
+    fn duration_since() {
+        let earlier = date(2024, 2, 28);
+        let later = DateTime::builder()
+            .year(2024)
+            .month(3)
+            .day(1)
+            .minute(30)
+            .build();
+
+        assert_eq!(
+            later.duration_since(&earlier),
+            Some(Duration::new(2.0 * 86_400.0 + 1_800.0))
+        );
+        assert_eq!(
+            earlier.duration_since(&later),
+            Some(Duration::new(-(2.0 * 86_400.0 + 1_800.0)))
+        );
+        assert_eq!(
+            DateTime::builder()
+                .year(2024)
+                .build()
+                .duration_since(&earlier),
+            None
+        );
+
+        let (far_future, far_past) = (date(200_000_000_000, 1, 1), date(-200_000_000_000, 1, 1));
+        assert!(far_future.to_epoch_seconds().is_ok());
+        assert!(far_past.to_epoch_seconds().is_ok());
+        assert_eq!(far_future.duration_since(&far_past), None);
+    }
+
+    #[test]
+    fn from_ymd_hms() {
+        let dt = DateTime::from_ymd_hms(2024, 2, 29, 23, 59, 59).unwrap();
+        assert_eq!(dt.present_fields(), 0b111111);
+        assert_eq!(DateTime::from_ymd(2024, 2, 29), Ok(date(2024, 2, 29)));
+
+        assert_eq!(
+            DateTime::from_ymd_hms(2023, 2, 29, 12, 60, 0),
+            Err("day: 29 is outside of [1, 28], minute: 60 is outside of [0, 59]".to_string())
+        );
+    }
+
+    #[test]
+    fn present_fields() {
+        assert_eq!(date(2024, 2, 29).present_fields(), 0b000111);
+
+        let time = DateTime::builder().hour(13).minute(5).second(0).build();
+        assert_eq!(time.present_fields(), 0b111000);
+
+        let full = DateTime::builder()
+            .year(2024)
+            .month(2)
+            .day(29)
+            .hour(13)
+            .minute(5)
+            .second(0)
+            .build();
+        assert_eq!(full.present_fields(), 0b111111);
+
+        assert_eq!(DateTime::default().present_fields(), 0);
+    }
+
+    #[test]
+    fn iso_week_previous_year() {
+        assert_eq!(date(2021, 1, 1).iso_week(), Some((2020, 53)));
+        assert_eq!(date(2023, 1, 1).iso_week(), Some((2022, 52)));
+    }
+
+    #[test]
+    fn iso_week_next_year() {
+        assert_eq!(date(2024, 12, 30).iso_week(), Some((2025, 1)));
+        assert_eq!(date(2008, 12, 29).iso_week(), Some((2009, 1)));
+    }
+
+    #[test]
+    fn iso_week_missing_fields() {
+        assert_eq!(DateTime::builder().year(2025).build().iso_week(), None);
+        assert_eq!(date(2025, 2, 30).iso_week(), None);
+    }
+
+    #[test]
+    fn to_epoch_seconds_date_only() {
+        assert_eq!(date(1970, 1, 1).to_epoch_seconds(), Ok(0));
+        assert_eq!(date(2000, 3, 1).to_epoch_seconds(), Ok(951_868_800));
+        assert_eq!(date(1969, 12, 31).to_epoch_seconds(), Ok(-86_400));
+    }
+
+    #[test]
+    fn to_epoch_seconds_missing_year() {
+        let datetime = DateTime::builder().month(1).day(1).build();
+
+        assert_eq!(
+            datetime.to_epoch_seconds(),
+            Err("DateTime is missing the year".to_string())
+        );
+        assert!(date(2025, 2, 29).to_epoch_seconds().is_err());
+    }
+
+    #[test]
+    fn to_epoch_seconds_extreme_years() {
+        assert_eq!(
+            date(i64::MAX, 12, 31).to_epoch_seconds(),
+            Err(format!("DateTime year {} is out of range", i64::MAX))
+        );
+        assert!(date(i64::MIN, 1, 1).to_epoch_seconds().is_err());
+        assert_eq!(date(i64::MIN, 1, 1).iso_week(), None);
+        assert_eq!(date(i64::MAX, 12, 31).iso_week(), None);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(date(2024, 2, 29).validate().is_ok());
+
+        let datetime = DateTime::builder()
+            .year(2025)
+            .month(2)
+            .day(29)
+            .hour(24)
+            .build();
+        assert_eq!(
+            datetime.validate().unwrap_err(),
+            vec![
+                "day: 29 is outside of [1, 28]",
+                "hour: 24 is outside of [0, 23]",
+            ]
+        );
+    }
+}
@@ -1,6 +1,11 @@
+use std::iter::Sum;
+use std::ops::Add;
+
 use serde::{Deserialize, Serialize};
 
-const TYPE_NAME: &str = "duration";
+use crate::validate::Problems;
+use crate::{Tagged, Validate};
+
 const SECONDS_IN_MINUTE: f64 = 60.0;
 const MINUTES_IN_HOUR: f64 = 60.0;
 const HOURS_IN_DAY: f64 = 24.0;
@@ -18,12 +23,16 @@ const DAYS_IN_WEEK: f64 = 7.0;
 /// assert_eq!(d.hours(), 1.0);
 /// ```
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "DurationCbor", into = "DurationCbor")]
 pub struct Duration {
     seconds: f64,
 }
 
 impl Duration {
+    /// A duration of zero seconds.
+    pub const ZERO: Duration = Duration::new(0.0);
+
     /// Creates a new `Duration` instance from the given seconds.
     ///
     /// # Arguments
@@ -131,8 +140,185 @@ impl Duration {
     pub const fn weeks(&self) -> f64 {
         self.days() / DAYS_IN_WEEK
     }
+
+    /// Creates a new `Duration` from a number of frames at the given frame rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The number of frames.
+    /// * `fps` - The frame rate in frames per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let d = Duration::from_frames(48.0, 24.0);
+    /// assert_eq!(d.seconds(), 2.0);
+    /// ```
+    pub const fn from_frames(frames: f64, fps: f64) -> Self {
+        Self::new(frames / fps)
+    }
+
+    /// Returns the duration as a (fractional) number of frames at the given frame rate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let d = Duration::new(1.5);
+    /// assert_eq!(d.to_frames(24.0), 36.0);
+    /// ```
+    pub const fn to_frames(&self, fps: f64) -> f64 {
+        self.seconds * fps
+    }
+
+    /// Returns the index of the frame nearest to the duration at the given frame rate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let d = Duration::new(1.01);
+    /// assert_eq!(d.to_frame_index(24.0), 24);
+    /// ```
+    pub fn to_frame_index(&self, fps: f64) -> i64 {
+        self.to_frames(fps).round() as i64
+    }
+
+    /// Formats the duration as a zero-padded `HH:MM:SS` timecode.
+    ///
+    /// Fractional seconds are rounded to milliseconds and appended as `.mmm`
+    /// when present. Hours keep counting past 24 instead of rolling over into
+    /// days, and negative durations are prefixed with `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// assert_eq!(Duration::new(3661.0).to_timecode(), "01:01:01");
+    /// assert_eq!(Duration::new(90000.25).to_timecode(), "25:00:00.250");
+    /// ```
+    pub fn to_timecode(&self) -> String {
+        let sign = if self.seconds < 0.0 { "-" } else { "" };
+        let millis = (self.seconds.abs() * 1000.0).round() as u64;
+
+        let seconds = millis / 1000;
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        let timecode = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+
+        match millis % 1000 {
+            0 => timecode,
+            millis => format!("{timecode}.{millis:03}"),
+        }
+    }
+
+    /// Rounds the duration to the nearest multiple of `unit`.
+    ///
+    /// Halfway cases round away from zero. The sign of `unit` is ignored, and a
+    /// zero or non-finite `unit` leaves the duration unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let minute = Duration::new(60.0);
+    /// assert_eq!(Duration::new(95.0).round_to(minute), Duration::new(120.0));
+    /// assert_eq!(Duration::new(95.0).round_to(Duration::ZERO), Duration::new(95.0));
+    /// ```
+    pub fn round_to(&self, unit: Duration) -> Duration {
+        self.snap_to(unit, f64::round)
+    }
+
+    /// Rounds the duration down to a multiple of `unit`, see [`Duration::round_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let minute = Duration::new(60.0);
+    /// assert_eq!(Duration::new(119.0).floor_to(minute), Duration::new(60.0));
+    /// ```
+    pub fn floor_to(&self, unit: Duration) -> Duration {
+        self.snap_to(unit, f64::floor)
+    }
+
+    /// Rounds the duration up to a multiple of `unit`, see [`Duration::round_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let minute = Duration::new(60.0);
+    /// assert_eq!(Duration::new(61.0).ceil_to(minute), Duration::new(120.0));
+    /// ```
+    pub fn ceil_to(&self, unit: Duration) -> Duration {
+        self.snap_to(unit, f64::ceil)
+    }
+
+    /// Adds two durations, returning `None` if the result is infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let d = Duration::new(30.0).checked_add(&Duration::new(15.0));
+    /// assert_eq!(d, Some(Duration::new(45.0)));
+    /// assert_eq!(Duration::new(f64::MAX).checked_add(&Duration::new(f64::MAX)), None);
+    /// ```
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        Self::finite(self.seconds + other.seconds)
+    }
+
+    /// Subtracts `other` from this duration, returning `None` if the result is
+    /// infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Duration;
+    ///
+    /// let d = Duration::new(30.0).checked_sub(&Duration::new(45.0));
+    /// assert_eq!(d, Some(Duration::new(-15.0)));
+    /// ```
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        Self::finite(self.seconds - other.seconds)
+    }
+
+    fn finite(seconds: f64) -> Option<Duration> {
+        seconds.is_finite().then(|| Duration::new(seconds))
+    }
+
+    fn snap_to(&self, unit: Duration, round: fn(f64) -> f64) -> Duration {
+        let unit = unit.seconds.abs();
+        if unit == 0.0 || !unit.is_finite() {
+            return self.clone();
+        }
+
+        Duration::new(round(self.seconds / unit) * unit)
+    }
 }
 
+/// A builder for a `Duration` made up of several components.
+///
+/// The components are summed when building, so negative components subtract
+/// from the total. A component set twice keeps only the last value.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::Duration;
+///
+/// let d = Duration::builder().hours(1.0).minutes(-15.0).build();
+/// assert_eq!(d.minutes(), 45.0);
+/// ```
 pub struct DurationBuilder {
     seconds: f64,
     minutes: f64,
@@ -247,9 +433,66 @@ impl DurationBuilder {
     }
 }
 
+impl From<f64> for Duration {
+    fn from(seconds: f64) -> Self {
+        Duration::new(seconds)
+    }
+}
+
+impl From<Duration> for f64 {
+    fn from(duration: Duration) -> Self {
+        duration.seconds
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        Duration::new(self.seconds + rhs.seconds)
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Self {
+        iter.fold(Duration::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.fold(Duration::ZERO, |total, duration| {
+            Duration::new(total.seconds + duration.seconds)
+        })
+    }
+}
+
+impl Tagged for Duration {
+    const TYPE_NAME: &'static str = "duration";
+}
+
+impl Validate for Duration {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.check(self.seconds.is_finite(), || {
+            format!("{} is not finite", self.seconds)
+        });
+        problems.finish()
+    }
+}
+
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct DurationCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Duration, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Duration>")
+    )]
     typwire_type: String,
     seconds: f64,
 }
@@ -258,7 +501,7 @@ struct DurationCbor {
 impl From<Duration> for DurationCbor {
     fn from(value: Duration) -> Self {
         Self {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Duration::TYPE_NAME.to_string(),
             seconds: value.seconds,
         }
     }
@@ -269,13 +512,6 @@ impl TryFrom<DurationCbor> for Duration {
     type Error = String;
 
     fn try_from(value: DurationCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for Duration: {}",
-                value.typwire_type
-            ));
-        }
-
         Ok(Self {
             seconds: value.seconds,
         })
@@ -296,4 +532,81 @@ mod tests {
 
         assert_eq!(Duration { seconds: 3724.0 }, duration)
     }
+
+    #[test]
+    fn builder_negative_components() {
+        let duration = Duration::builder().hours(1.0).minutes(-15.0).build();
+        assert_eq!(duration.seconds(), 2700.0);
+
+        let duration = Duration::builder().days(-1.0).hours(1.0).build();
+        assert_eq!(duration.hours(), -23.0);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = Duration::new(90.0);
+        let b = Duration::new(30.0);
+        assert_eq!(a.checked_add(&b), Some(Duration::new(120.0)));
+        assert_eq!(a.checked_sub(&b), Some(Duration::new(60.0)));
+
+        let infinite = Duration::new(f64::INFINITY);
+        assert_eq!(infinite.checked_add(&b), None);
+        assert_eq!(a.checked_sub(&infinite), None);
+        assert_eq!(infinite.checked_sub(&infinite), None);
+    }
+
+    #[test]
+    fn snap_to_unit() {
+        let minute = Duration::new(60.0);
+        let cases = [
+            (89.0, 60.0, 60.0, 120.0),
+            (90.0, 120.0, 60.0, 120.0),
+            (-90.0, -120.0, -120.0, -60.0),
+            (120.0, 120.0, 120.0, 120.0),
+        ];
+
+        for (seconds, round, floor, ceil) in cases {
+            let duration = Duration::new(seconds);
+            assert_eq!(duration.round_to(minute.clone()), Duration::new(round));
+            assert_eq!(duration.floor_to(minute.clone()), Duration::new(floor));
+            assert_eq!(duration.ceil_to(Duration::new(-60.0)), Duration::new(ceil));
+        }
+
+        let duration = Duration::new(12.5);
+        assert_eq!(duration.round_to(Duration::ZERO), duration);
+        assert_eq!(duration.floor_to(Duration::new(f64::INFINITY)), duration);
+        assert_eq!(duration.ceil_to(Duration::new(f64::NAN)), duration);
+    }
+
+    #[test]
+    fn to_timecode() {
+        assert_eq!(Duration::new(3661.0).to_timecode(), "01:01:01");
+        assert_eq!(Duration::new(90.5).to_timecode(), "00:01:30.500");
+        assert_eq!(Duration::new(59.9999).to_timecode(), "00:01:00");
+        assert_eq!(Duration::new(-61.0).to_timecode(), "-00:01:01");
+    }
+
+    #[test]
+    fn from_seconds() {
+        let duration: Duration = 90.0.into();
+        assert_eq!(duration, Duration::new(90.0));
+
+        let seconds: f64 = duration.into();
+        assert_eq!(seconds, 90.0);
+    }
+
+    #[test]
+    fn sum() {
+        let durations = vec![
+            Duration::new(30.0),
+            Duration::new(60.0),
+            Duration::new(90.0),
+        ];
+
+        assert_eq!(durations.iter().sum::<Duration>(), Duration::new(180.0));
+        assert_eq!(
+            durations.into_iter().sum::<Duration>(),
+            Duration::new(180.0)
+        );
+    }
 }
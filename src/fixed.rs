@@ -0,0 +1,48 @@
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The number of fixed-point steps per unit, i.e. a resolution of `2^-16`.
+///
+/// Ratios are encoded in steps of `2^-16`, angles in steps of `2^-16` radians
+/// and lengths in steps of `2^-16` points, covering values of magnitude up to
+/// `32768`.
+pub(crate) const SCALE: f64 = 65536.0;
+
+/// Serializes a float as a fixed-point integer.
+///
+/// Fails for values that are not finite or do not fit into an `i32` once
+/// scaled, rather than silently saturating.
+pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    let scaled = (value * SCALE).round();
+    if !(f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&scaled) {
+        return Err(S::Error::custom(format!(
+            "{value} is outside of the fixed-point range [-32768, 32768)"
+        )));
+    }
+
+    (scaled as i32).serialize(serializer)
+}
+
+/// Deserializes a fixed-point integer.
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    i32::deserialize(deserializer).map(|value| f64::from(value) / SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, Length, Ratio, ToBytes as _};
+
+    #[test]
+    fn serialize_out_of_range() {
+        assert!(Length::new(32_767.0).to_bytes().is_ok());
+        assert!(Length::new(-32_768.0).to_bytes().is_ok());
+
+        assert_eq!(
+            Length::new(40_000.0).to_bytes(),
+            Err("40000 is outside of the fixed-point range [-32768, 32768)".to_string())
+        );
+        assert!(Angle::new(-1e6).to_bytes().is_err());
+        assert!(Ratio::new(f64::NAN).to_bytes().is_err());
+        assert!(Ratio::new(f64::INFINITY).to_bytes().is_err());
+    }
+}
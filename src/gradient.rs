@@ -1,18 +1,34 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{Angle, Center, Ratio, Stop};
+use crate::lerp::{Span, find_span};
+use crate::validate::Problems;
+use crate::{Angle, Center, Color, Lerp, Luma, Oklch, Ratio, Rgb, Stop, Validate, Value};
+
+/// Tolerance used when checking that the focal circle lies within the main circle.
+const FOCAL_EPSILON: f64 = 1e-9;
 
 /// Represents different types of gradients with specific parameters for each type.
 ///
+/// The `Debug` output is compact, e.g. `linear[oklab] 45deg {black@0, white@1}`
+/// with angles in degrees. The alternate form `{:#?}` prints the full structure.
+///
 /// # Examples
 /// ```
 /// use typwire::{Angle, Center, ColorSpace, Gradient, Ratio, Stop};
 ///
 /// let linear_gradient = Gradient::linear(vec![], Angle::new(45.0), ColorSpace::Oklab);
 /// ```
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(tag = "typwire-type", rename_all_fields = "kebab-case")]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(with = "GradientCbor"))]
+#[serde(
+    tag = "typwire-type",
+    rename_all_fields = "kebab-case",
+    try_from = "Value"
+)]
 pub enum Gradient {
     #[serde(rename = "gradient-linear")]
     Linear {
@@ -145,25 +161,1313 @@ impl Gradient {
         }
     }
 
-    /// Returns a builder for creating a conic gradient.
+    /// Returns a builder for creating a conic gradient.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Center, Gradient, Ratio};
+    ///
+    /// let builder = Gradient::conic_builder();
+    /// ```
+    pub fn conic_builder() -> ConicGradientBuilder {
+        ConicGradientBuilder {
+            stops: vec![],
+            angle: None,
+            center: None,
+            space: ColorSpace::default(),
+        }
+    }
+
+    /// Creates a left-to-right linear gradient spanning the full hue wheel.
+    ///
+    /// The `stops` colors are spaced evenly from 0° to 360° in Oklch at a
+    /// constant lightness of 70% and chroma of 0.15, so the first and last
+    /// stops are the same color and the gradient can be tiled seamlessly.
+    /// At least two stops are always created.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::rainbow(ColorSpace::Oklch, 7);
+    /// let stops = gradient.stops();
+    /// assert_eq!(stops.len(), 7);
+    /// assert_eq!(stops[0].color, stops[6].color);
+    /// ```
+    pub fn rainbow(space: ColorSpace, stops: usize) -> Gradient {
+        let last = stops.max(2) - 1;
+        let stops = (0..=last)
+            .map(|index| {
+                let t = index as f64 / last as f64;
+                let hue = Angle::new(t * std::f64::consts::TAU).normalized();
+                let color = Oklch::new(Ratio::new(0.7), Ratio::new(0.15), hue, Ratio::new(1.0));
+
+                Stop::new(color.into(), Ratio::new(t))
+            })
+            .collect();
+
+        Gradient::linear(stops, Angle::new(0.0), space)
+    }
+
+    /// Creates a left-to-right linear gradient from `start` to `end` with
+    /// intermediate stops baked in Oklch.
+    ///
+    /// The `steps` stops are spaced evenly and interpolated along the shorter
+    /// hue arc, which avoids the muddy midtones of interpolating in RGB. Since
+    /// the intermediate colors are baked into the stops, the gradient looks
+    /// similar even in renderers that interpolate linearly between them. At
+    /// least two stops are always created.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{ColorSpace, Gradient, color};
+    ///
+    /// let gradient = Gradient::perceptual(color::RED, color::BLUE, 5);
+    /// assert_eq!(gradient.stops().len(), 5);
+    /// assert_eq!(gradient.space(), ColorSpace::Oklch);
+    /// ```
+    pub fn perceptual(start: Color, end: Color, steps: usize) -> Gradient {
+        let last = steps.max(2) - 1;
+        let stops = (0..=last)
+            .map(|index| {
+                let t = Ratio::new(index as f64 / last as f64);
+                Stop::new(start.mix(&end, t, ColorSpace::Oklch), t)
+            })
+            .collect();
+
+        Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklch)
+    }
+
+    /// Parses a gradient from CSS syntax.
+    ///
+    /// Supports `linear-gradient(...)`, `radial-gradient(...)` and
+    /// `conic-gradient(...)` with any color [`Color::parse`] accepts and
+    /// optional percentage offsets within `[0%, 100%]`. Directions may be given
+    /// as angles (`deg`, `rad`, `grad`, `turn`) or as `to <side>`; radial and
+    /// conic gradients accept an `at <x>% <y>%` center and conic gradients a
+    /// `from <angle>` start. Radial gradients take an optional size keyword
+    /// (`closest-side`, `farthest-corner`, ...) or percentage radius. They scale
+    /// with their box like a CSS `ellipse`, so the `circle` shape is rejected.
+    ///
+    /// CSS angles point clockwise from the top, while Typst angles start at the
+    /// positive x-axis, so parsed angles are rotated by `-90°`. As in CSS, the
+    /// gradient interpolates in the RGB color space.
+    ///
+    /// # Errors
+    /// Returns a string error message for unsupported or malformed syntax.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Gradient, Ratio, color};
+    ///
+    /// let gradient = Gradient::from_css("linear-gradient(90deg, black, white 100%)").unwrap();
+    /// assert_eq!(gradient.stops()[0].color, color::BLACK);
+    /// assert_eq!(gradient.stops()[1].offset, Ratio::new(1.0));
+    /// ```
+    pub fn from_css(css: &str) -> Result<Gradient, String> {
+        crate::css::parse_gradient(css)
+    }
+
+    /// Returns the color stops of the gradient.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+    /// assert!(gradient.stops().is_empty());
+    /// ```
+    pub fn stops(&self) -> &[Stop] {
+        match self {
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } | Self::Conic { stops, .. } => {
+                stops
+            }
+        }
+    }
+
+    /// Returns copies of the color stops with their colors converted to `space`.
+    ///
+    /// The gradient itself, including its interpolation space, is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let stops = vec![Stop::new(color::RED, Ratio::new(0.0))];
+    /// let gradient = Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklab);
+    /// let converted = gradient.stops_in_space(ColorSpace::Oklch);
+    /// assert_eq!(converted[0].color.space(), ColorSpace::Oklch);
+    /// assert_eq!(gradient.stops()[0].color, color::RED);
+    /// ```
+    pub fn stops_in_space(&self, space: ColorSpace) -> Vec<Stop> {
+        self.stops()
+            .iter()
+            .map(|stop| Stop::new(stop.color.to_space(space), stop.offset))
+            .collect()
+    }
+
+    /// Returns the color space in which the gradient interpolates.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Rgb);
+    /// assert_eq!(gradient.space(), ColorSpace::Rgb);
+    /// ```
+    pub const fn space(&self) -> ColorSpace {
+        match self {
+            Self::Linear { space, .. } | Self::Radial { space, .. } | Self::Conic { space, .. } => {
+                *space
+            }
+        }
+    }
+
+    /// Returns the center of a radial or conic gradient.
+    ///
+    /// Returns `None` for linear gradients.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Center, ColorSpace, Gradient, Ratio};
+    ///
+    /// let center = Center::new(Ratio::new(0.25), Ratio::new(0.5));
+    /// let gradient = Gradient::conic(vec![], Angle::new(0.0), center.clone(), ColorSpace::Oklab);
+    /// assert_eq!(gradient.center(), Some(&center));
+    /// ```
+    pub const fn center(&self) -> Option<&Center> {
+        match self {
+            Self::Radial { center, .. } | Self::Conic { center, .. } => Some(center),
+            Self::Linear { .. } => None,
+        }
+    }
+
+    /// Returns the focal center of a radial gradient.
+    ///
+    /// Returns `None` for linear and conic gradients.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+    /// assert_eq!(gradient.focal_center(), None);
+    /// ```
+    pub const fn focal_center(&self) -> Option<&Center> {
+        match self {
+            Self::Radial { focal_center, .. } => Some(focal_center),
+            Self::Linear { .. } | Self::Conic { .. } => None,
+        }
+    }
+
+    /// Returns the direction of a linear gradient as a unit vector `(dx, dy)`.
+    ///
+    /// As in Typst, `0°` points right and the y-axis points down, so angles
+    /// grow clockwise and `90°` points down. Returns `None` for radial and
+    /// conic gradients.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+    /// assert_eq!(gradient.direction(), Some((1.0, 0.0)));
+    /// ```
+    pub fn direction(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::Linear { angle, .. } => {
+                let (sin, cos) = angle.rad().sin_cos();
+                Some((cos, sin))
+            }
+            Self::Radial { .. } | Self::Conic { .. } => None,
+        }
+    }
+
+    /// Samples the gradient at the given offset.
+    ///
+    /// The stops are expected in ascending offset order. Offsets before the first
+    /// or after the last stop yield the color of that stop. The returned color is
+    /// expressed in the gradient's color space. A gradient with a single stop
+    /// always yields that stop's color, one without stops yields transparent black.
+    ///
+    /// Like browsers, stops with differing alpha are interpolated with their
+    /// color premultiplied by alpha, so fading into a transparent stop does not
+    /// tint the result with its color. Hue-based spaces and CMYK are
+    /// interpolated as-is.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Color, ColorSpace, Gradient, Luma, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Luma,
+    /// );
+    /// assert_eq!(
+    ///     gradient.sample(Ratio::new(0.25)),
+    ///     Color::Luma(Luma::new(Ratio::new(0.25), Ratio::new(1.0)))
+    /// );
+    /// ```
+    pub fn sample(&self, offset: Ratio) -> Color {
+        self.sample_eased(offset, Easing::Linear)
+    }
+
+    /// Samples the gradient at the given offset, reshaping the transition between
+    /// the two surrounding stops with an easing curve.
+    ///
+    /// Apart from the easing, this behaves like [`Gradient::sample`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Color, ColorSpace, Easing, Gradient, Luma, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Luma,
+    /// );
+    /// assert_eq!(
+    ///     gradient.sample_eased(Ratio::new(0.5), Easing::EaseIn),
+    ///     Color::Luma(Luma::new(Ratio::new(0.25), Ratio::new(1.0)))
+    /// );
+    /// ```
+    pub fn sample_eased(&self, offset: Ratio, easing: Easing) -> Color {
+        sample_stops(self.stops(), self.space(), offset, easing)
+    }
+
+    /// Converts every stop's color into the gradient's color space once, for
+    /// sampling the gradient many times.
+    ///
+    /// The prepared gradient yields the same colors as [`Gradient::sample`], but
+    /// skips the per-sample color conversions. [`Gradient::sample_many`] and the
+    /// functions built on it use it internally.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::RED, Ratio::new(0.0)),
+    ///         Stop::new(color::BLUE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklch,
+    /// );
+    /// let prepared = gradient.prepared();
+    /// assert_eq!(prepared.sample(Ratio::new(0.3)), gradient.sample(Ratio::new(0.3)));
+    /// ```
+    pub fn prepared(&self) -> PreparedGradient {
+        let space = self.space();
+        let stops = self
+            .stops()
+            .iter()
+            .map(|stop| Stop::new(stop.color.to_space(space), stop.offset))
+            .collect();
+
+        PreparedGradient { stops, space }
+    }
+
+    /// Samples the gradient at the given offset into a caller-provided sRGB color.
+    ///
+    /// This is a shorthand for [`Gradient::sample`] followed by [`Color::to_rgb`].
+    /// To sample many offsets, convert the stops once with [`Gradient::prepared`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Rgb, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let mut rgb = Rgb::new(Ratio::new(0.0), Ratio::new(0.0), Ratio::new(0.0), Ratio::new(0.0));
+    /// gradient.sample_into(Ratio::new(1.0), &mut rgb);
+    /// assert_eq!(rgb.r.to_u8(), 255);
+    /// ```
+    pub fn sample_into(&self, offset: Ratio, out: &mut Rgb) {
+        *out = self.sample(offset).to_rgb();
+    }
+
+    /// Samples the gradient at `count` evenly spaced offsets from `0` to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Luma,
+    /// );
+    /// let samples = gradient.sample_many(3);
+    /// assert_eq!(samples.len(), 3);
+    /// assert_eq!(samples[2], color::WHITE);
+    /// ```
+    pub fn sample_many(&self, count: usize) -> Vec<Color> {
+        let step = if count > 1 {
+            1.0 / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        let prepared = self.prepared();
+        (0..count)
+            .map(|i| prepared.sample(Ratio::new(i as f64 * step)))
+            .collect()
+    }
+
+    /// Precomputes `size` evenly spaced samples of the gradient into a lookup table.
+    ///
+    /// Looking colors up in the table is much cheaper than sampling the
+    /// gradient, at the cost of precision. The table holds at least one entry.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let lut = gradient.build_lut(256);
+    /// assert_eq!(lut.sample(Ratio::new(1.0)), gradient.sample(Ratio::new(1.0)));
+    /// ```
+    pub fn build_lut(&self, size: usize) -> GradientLut {
+        GradientLut {
+            colors: self.sample_many(size.max(1)),
+        }
+    }
+
+    /// Renders the gradient into a strip of `width` sRGB pixels.
+    ///
+    /// Every kind of gradient is sampled by offset from `0` to `1`. Each pixel is
+    /// returned as `[r, g, b, a]` bytes, with out-of-gamut channels clipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let strip = gradient.render_strip(4);
+    /// assert_eq!(strip[0], [0, 0, 0, 255]);
+    /// assert_eq!(strip[3], [255, 255, 255, 255]);
+    /// ```
+    pub fn render_strip(&self, width: usize) -> Vec<[u8; 4]> {
+        self.sample_many(width)
+            .iter()
+            .map(|color| {
+                let rgb = color.to_rgb();
+                [
+                    rgb.r.to_u8(),
+                    rgb.g.to_u8(),
+                    rgb.b.to_u8(),
+                    rgb.alpha.to_u8(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Renders the gradient into a `width` × `height` RGBA8 image.
+    ///
+    /// Every row is the same strip produced by [`Gradient::render_strip`], so
+    /// radial and conic gradients are sampled along their offset axis. The
+    /// pixels are returned row by row as tightly packed `r, g, b, a` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let image = gradient.to_rgba_strip(8, 2);
+    /// assert_eq!(image.len(), 8 * 2 * 4);
+    /// ```
+    pub fn to_rgba_strip(&self, width: usize, height: usize) -> Vec<u8> {
+        self.render_strip(width).as_flattened().repeat(height)
+    }
+
+    /// Returns the angular position of every stop of a conic gradient.
+    ///
+    /// Each offset in `[0, 1]` is mapped to a full turn starting at the
+    /// gradient's base angle and normalized into `[0, 2π)`. Returns `None` for
+    /// linear and radial gradients.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Center, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::conic(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(0.5)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let angles = gradient.stop_angles().unwrap();
+    /// assert_eq!(angles[1], Angle::new(std::f64::consts::PI));
+    /// ```
+    pub fn stop_angles(&self) -> Option<Vec<Angle>> {
+        let Self::Conic { stops, angle, .. } = self else {
+            return None;
+        };
+
+        let angles = stops
+            .iter()
+            .map(|stop| {
+                Angle::new(angle.rad() + stop.offset.ratio * std::f64::consts::TAU).normalized()
+            })
+            .collect();
+
+        Some(angles)
+    }
+
+    /// Returns a mutable reference to the color stops of the gradient.
+    fn stops_mut(&mut self) -> &mut Vec<Stop> {
+        match self {
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } | Self::Conic { stops, .. } => {
+                stops
+            }
+        }
+    }
+
+    /// Returns `true` if two adjacent stops share the same offset, creating a
+    /// hard color edge.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::BLACK, Ratio::new(0.5)),
+    ///         Stop::new(color::WHITE, Ratio::new(0.5)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert!(gradient.has_hard_stops());
+    /// ```
+    pub fn has_hard_stops(&self) -> bool {
+        self.stops()
+            .windows(2)
+            .any(|pair| pair[0].offset == pair[1].offset)
+    }
+
+    /// Returns a copy of the gradient in which adjacent stops whose offsets differ
+    /// by at most `epsilon` are collapsed into the last of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::BLACK, Ratio::new(0.5)),
+    ///         Stop::new(color::WHITE, Ratio::new(0.5001)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let deduped = gradient.dedupe_offsets(0.001);
+    /// assert_eq!(deduped.stops().len(), 3);
+    /// assert_eq!(deduped.stops()[1].color, color::WHITE);
+    /// ```
+    pub fn dedupe_offsets(&self, epsilon: f64) -> Gradient {
+        let mut stops: Vec<Stop> = Vec::with_capacity(self.stops().len());
+        for stop in self.stops() {
+            match stops.last_mut() {
+                Some(last) if (stop.offset.ratio - last.offset.ratio).abs() <= epsilon => {
+                    *last = stop.clone();
+                }
+                _ => stops.push(stop.clone()),
+            }
+        }
+
+        let mut gradient = self.clone();
+        *gradient.stops_mut() = stops;
+
+        gradient
+    }
+
+    /// Fades the gradient by multiplying the alpha of every stop by `factor`.
+    ///
+    /// See [`Color::scale_alpha`] for how each stop's color is affected.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let stops = vec![Stop::new(color::RED, Ratio::new(0.0))];
+    /// let gradient = Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklab);
+    /// let faded = gradient.scale_alpha(Ratio::new(0.5));
+    /// assert_eq!(faded.stops()[0].color.alpha(), Ratio::new(0.5));
+    /// ```
+    pub fn scale_alpha(&self, factor: Ratio) -> Gradient {
+        let mut gradient = self.clone();
+        for stop in gradient.stops_mut() {
+            stop.color = stop.color.scale_alpha(factor);
+        }
+
+        gradient
+    }
+
+    /// Fades the gradient to the given opacity, an alias of [`Gradient::scale_alpha`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let stops = vec![Stop::new(color::RED, Ratio::new(0.0))];
+    /// let gradient = Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklab);
+    /// let faded = gradient.with_opacity(Ratio::new(0.5));
+    /// assert_eq!(faded, gradient.scale_alpha(Ratio::new(0.5)));
+    /// ```
+    pub fn with_opacity(&self, opacity: Ratio) -> Gradient {
+        self.scale_alpha(opacity)
+    }
+
+    /// Returns a copy of the gradient rotated by `by`.
+    ///
+    /// The angle of linear and conic gradients is increased by `by` and
+    /// normalized into `[0, 2π)`. Radial gradients have no angle and are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+    /// let rotated = gradient.rotate(Angle::new(-std::f64::consts::FRAC_PI_2));
+    /// assert_eq!(
+    ///     rotated,
+    ///     Gradient::linear(vec![], Angle::new(1.5 * std::f64::consts::PI), ColorSpace::Oklab)
+    /// );
+    /// ```
+    pub fn rotate(&self, by: Angle) -> Gradient {
+        let mut gradient = self.clone();
+        if let Self::Linear { angle, .. } | Self::Conic { angle, .. } = &mut gradient {
+            *angle = Angle::new(angle.rad() + by.rad()).normalized();
+        }
+
+        gradient
+    }
+
+    /// Reinterprets the gradient as a conic gradient around `center`.
+    ///
+    /// The stops and color space are kept. The conic gradient starts at the
+    /// angle of a linear or conic gradient, or at `0` for a radial gradient.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Center, ColorSpace, Gradient, Ratio};
+    ///
+    /// let linear = Gradient::linear(vec![], Angle::new(1.0), ColorSpace::Oklab);
+    /// let center = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+    /// assert_eq!(
+    ///     linear.as_conic(center.clone()),
+    ///     Gradient::conic(vec![], Angle::new(1.0), center, ColorSpace::Oklab)
+    /// );
+    /// ```
+    pub fn as_conic(&self, center: Center) -> Gradient {
+        let angle = match self {
+            Self::Linear { angle, .. } | Self::Conic { angle, .. } => angle.clone(),
+            Self::Radial { .. } => Angle::new(0.0),
+        };
+
+        Gradient::conic(self.stops().to_vec(), angle, center, self.space())
+    }
+
+    /// Reinterprets the gradient as a linear gradient along `angle`.
+    ///
+    /// The stops and color space are kept, while any center or radius is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Center, ColorSpace, Gradient, Ratio};
+    ///
+    /// let conic = Gradient::conic(
+    ///     vec![],
+    ///     Angle::new(0.0),
+    ///     Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert_eq!(
+    ///     conic.as_linear(Angle::new(2.0)),
+    ///     Gradient::linear(vec![], Angle::new(2.0), ColorSpace::Oklab)
+    /// );
+    /// ```
+    pub fn as_linear(&self, angle: Angle) -> Gradient {
+        Gradient::linear(self.stops().to_vec(), angle, self.space())
+    }
+
+    /// Returns the most vivid stop, i.e. the one with the highest Oklch chroma.
+    ///
+    /// The first of several equally vivid stops is returned. Returns `None` for a
+    /// gradient without stops.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::GRAY, Ratio::new(0.0)),
+    ///         Stop::new(color::FUCHSIA, Ratio::new(0.5)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert_eq!(gradient.most_saturated_stop().unwrap().color, color::FUCHSIA);
+    /// ```
+    pub fn most_saturated_stop(&self) -> Option<&Stop> {
+        self.stop_by_max(|stop| stop.color.to_oklch().chroma.ratio)
+    }
+
+    /// Returns the lightest stop by Oklch lightness.
+    ///
+    /// The first of several equally light stops is returned. Returns `None` for a
+    /// gradient without stops.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::NAVY, Ratio::new(0.0)),
+    ///         Stop::new(color::YELLOW, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert_eq!(gradient.lightest_stop().unwrap().color, color::YELLOW);
+    /// ```
+    pub fn lightest_stop(&self) -> Option<&Stop> {
+        self.stop_by_max(|stop| stop.color.to_oklch().lightness.ratio)
+    }
+
+    /// Returns the darkest stop by Oklch lightness.
+    ///
+    /// The first of several equally dark stops is returned. Returns `None` for a
+    /// gradient without stops.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::NAVY, Ratio::new(0.0)),
+    ///         Stop::new(color::YELLOW, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert_eq!(gradient.darkest_stop().unwrap().color, color::NAVY);
+    /// ```
+    pub fn darkest_stop(&self) -> Option<&Stop> {
+        self.stop_by_max(|stop| -stop.color.to_oklch().lightness.ratio)
+    }
+
+    /// Returns the first stop maximizing `key`, skipping NaN keys where possible.
+    fn stop_by_max(&self, key: impl Fn(&Stop) -> f64) -> Option<&Stop> {
+        self.stops()
+            .iter()
+            .map(|stop| (key(stop), stop))
+            .reduce(|best, next| {
+                if next.0 > best.0 || best.0.is_nan() {
+                    next
+                } else {
+                    best
+                }
+            })
+            .map(|(_, stop)| stop)
+    }
+
+    /// Returns the smallest and largest stop offsets of the gradient.
+    ///
+    /// The stops do not need to be sorted. A gradient without stops covers
+    /// the empty span `(0, 0)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.7)),
+    ///         Stop::new(color::WHITE, Ratio::new(0.3)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert_eq!(gradient.offset_span(), (Ratio::new(0.3), Ratio::new(0.7)));
+    /// ```
+    pub fn offset_span(&self) -> (Ratio, Ratio) {
+        if self.stops().is_empty() {
+            return (Ratio::new(0.0), Ratio::new(0.0));
+        }
+
+        let (min, max) = self
+            .stops()
+            .iter()
+            .map(|stop| stop.offset.ratio)
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), offset| {
+                (min.min(offset), max.max(offset))
+            });
+
+        (Ratio::new(min), Ratio::new(max))
+    }
+
+    /// Checks that the gradient is well-formed, stopping at the first problem.
+    ///
+    /// Every stop must be [valid](Stop::is_valid) and the offsets must be in
+    /// ascending order. For radial gradients, the focal circle must additionally
+    /// lie within the main circle. Use [`Validate::validate`] to collect every
+    /// problem instead.
+    ///
+    /// # Errors
+    /// Returns a `GradientBuilderError` describing the first problem found. Stop
+    /// problems carry the index of the offending stop.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Center, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::radial(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+    ///     Ratio::new(0.5),
+    ///     Center::new(Ratio::new(1.2), Ratio::new(0.5)),
+    ///     Ratio::new(0.0),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// assert!(gradient.check().is_err());
+    /// ```
+    pub fn check(&self) -> Result<(), GradientBuilderError> {
+        let mut previous = 0.0;
+        for (index, stop) in self.stops().iter().enumerate() {
+            if !stop.is_valid() {
+                return Err(GradientBuilderError::InvalidOffset(index));
+            }
+            if stop.offset.ratio < previous {
+                return Err(GradientBuilderError::UnorderedStops(index));
+            }
+            previous = stop.offset.ratio;
+        }
+
+        if let Self::Radial {
+            center,
+            radius,
+            focal_center,
+            focal_radius,
+            ..
+        } = self
+        {
+            let distance = (focal_center.x.ratio - center.x.ratio)
+                .hypot(focal_center.y.ratio - center.y.ratio);
+            if distance + focal_radius.ratio > radius.ratio + FOCAL_EPSILON {
+                return Err(GradientBuilderError::InvalidFocal);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Interpolates between two gradients of the same kind and stop count.
+    ///
+    /// Every stop's color is mixed and its offset interpolated, as are the
+    /// angles, centers and radii. Colors are mixed in this gradient's color
+    /// space, which the result keeps. Angles are interpolated linearly, without
+    /// wrapping around.
+    ///
+    /// # Errors
+    /// Returns a string error message if the gradients are of different kinds
+    /// or have a different number of stops.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let stops = vec![
+    ///     Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///     Stop::new(color::WHITE, Ratio::new(1.0)),
+    /// ];
+    /// let from = Gradient::linear(stops.clone(), Angle::new(0.0), ColorSpace::Oklab);
+    /// let to = Gradient::linear(stops, Angle::new(1.0), ColorSpace::Oklab);
+    ///
+    /// let Gradient::Linear { angle, .. } = from.lerp(&to, 0.5).unwrap() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(angle, Angle::new(0.5));
+    /// ```
+    pub fn lerp(&self, other: &Gradient, t: f64) -> Result<Gradient, String> {
+        let (from, to) = (self.stops(), other.stops());
+        if from.len() != to.len() {
+            return Err(format!(
+                "Cannot interpolate between gradients with {} and {} stops",
+                from.len(),
+                to.len()
+            ));
+        }
+
+        let space = self.space();
+        let stops = from
+            .iter()
+            .zip(to)
+            .map(|(a, b)| {
+                Stop::new(
+                    Stop::interpolate(a, b, Ratio::new(t), space),
+                    a.offset.lerp(&b.offset, t),
+                )
+            })
+            .collect();
+
+        match (self, other) {
+            (Self::Linear { angle: a, .. }, Self::Linear { angle: b, .. }) => {
+                Ok(Self::linear(stops, a.lerp(b, t), space))
+            }
+            (
+                Self::Radial {
+                    center: a_center,
+                    radius: a_radius,
+                    focal_center: a_focal_center,
+                    focal_radius: a_focal_radius,
+                    ..
+                },
+                Self::Radial {
+                    center: b_center,
+                    radius: b_radius,
+                    focal_center: b_focal_center,
+                    focal_radius: b_focal_radius,
+                    ..
+                },
+            ) => Ok(Self::radial(
+                stops,
+                a_center.lerp(b_center, t),
+                a_radius.lerp(b_radius, t),
+                a_focal_center.lerp(b_focal_center, t),
+                a_focal_radius.lerp(b_focal_radius, t),
+                space,
+            )),
+            (
+                Self::Conic {
+                    angle: a_angle,
+                    center: a_center,
+                    ..
+                },
+                Self::Conic {
+                    angle: b_angle,
+                    center: b_center,
+                    ..
+                },
+            ) => Ok(Self::conic(
+                stops,
+                a_angle.lerp(b_angle, t),
+                a_center.lerp(b_center, t),
+                space,
+            )),
+            _ => Err("Cannot interpolate between gradients of different kinds".to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "typwire-type", rename_all_fields = "kebab-case")]
+enum GradientCbor {
+    #[serde(rename = "gradient-linear")]
+    Linear {
+        stops: Vec<Stop>,
+        angle: Angle,
+        space: ColorSpace,
+    },
+    #[serde(rename = "gradient-radial")]
+    Radial {
+        stops: Vec<Stop>,
+        center: Center,
+        radius: Ratio,
+        focal_center: Center,
+        focal_radius: Ratio,
+        space: ColorSpace,
+    },
+    #[serde(rename = "gradient-conic")]
+    Conic {
+        stops: Vec<Stop>,
+        angle: Angle,
+        center: Center,
+        space: ColorSpace,
+    },
+}
+
+impl fmt::Debug for Gradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return match self {
+                Self::Linear {
+                    stops,
+                    angle,
+                    space,
+                } => f
+                    .debug_struct("Linear")
+                    .field("stops", stops)
+                    .field("angle", angle)
+                    .field("space", space)
+                    .finish(),
+                Self::Radial {
+                    stops,
+                    center,
+                    radius,
+                    focal_center,
+                    focal_radius,
+                    space,
+                } => f
+                    .debug_struct("Radial")
+                    .field("stops", stops)
+                    .field("center", center)
+                    .field("radius", radius)
+                    .field("focal_center", focal_center)
+                    .field("focal_radius", focal_radius)
+                    .field("space", space)
+                    .finish(),
+                Self::Conic {
+                    stops,
+                    angle,
+                    center,
+                    space,
+                } => f
+                    .debug_struct("Conic")
+                    .field("stops", stops)
+                    .field("angle", angle)
+                    .field("center", center)
+                    .field("space", space)
+                    .finish(),
+            };
+        }
+
+        let point = |center: &Center| format!("({}, {})", center.x.ratio, center.y.ratio);
+        match self {
+            Self::Linear { angle, space, .. } => {
+                write!(f, "linear[{}] {}deg", space.name(), angle.deg())?;
+            }
+            Self::Radial {
+                center,
+                radius,
+                focal_center,
+                focal_radius,
+                space,
+                ..
+            } => {
+                write!(
+                    f,
+                    "radial[{}] at {} r={} focal {} r={}",
+                    space.name(),
+                    point(center),
+                    radius.ratio,
+                    point(focal_center),
+                    focal_radius.ratio
+                )?;
+            }
+            Self::Conic {
+                angle,
+                center,
+                space,
+                ..
+            } => {
+                write!(
+                    f,
+                    "conic[{}] {}deg at {}",
+                    space.name(),
+                    angle.deg(),
+                    point(center)
+                )?;
+            }
+        }
+
+        f.write_str(" {")?;
+        for (index, stop) in self.stops().iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}@{}", stop.color, stop.offset.ratio)?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl Validate for Gradient {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+
+        let stops = self.stops().len();
+        problems.check(stops >= 2, || {
+            format!("stops: gradient needs at least two stops, got {stops}")
+        });
+
+        let mut previous = 0.0;
+        for (index, stop) in self.stops().iter().enumerate() {
+            problems.nested(format_args!("stops[{index}]"), stop);
+
+            let offset = stop.offset.ratio;
+            problems.check(offset >= previous, || {
+                GradientBuilderError::UnorderedStops(index).to_string()
+            });
+            previous = offset.max(previous);
+        }
+
+        match self {
+            Self::Linear { angle, .. } => problems.nested("angle", angle),
+            Self::Radial {
+                center,
+                radius,
+                focal_center,
+                focal_radius,
+                ..
+            } => {
+                problems.nested("center", center);
+                problems.nested("radius", radius);
+                problems.nested("focal-center", focal_center);
+                problems.nested("focal-radius", focal_radius);
+
+                let distance = (focal_center.x.ratio - center.x.ratio)
+                    .hypot(focal_center.y.ratio - center.y.ratio);
+                problems.check(
+                    distance + focal_radius.ratio <= radius.ratio + FOCAL_EPSILON,
+                    || GradientBuilderError::InvalidFocal.to_string(),
+                );
+            }
+            Self::Conic { angle, center, .. } => {
+                problems.nested("angle", angle);
+                problems.nested("center", center);
+            }
+        }
+
+        problems.finish()
+    }
+}
+
+// Deserialize from GradientCbor, accepting the legacy type key
+impl TryFrom<Value> for Gradient {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let cbor: GradientCbor = crate::normalize_type_key(value)
+            .deserialized()
+            .map_err(crate::value_error_message)?;
+
+        // Mirror the builders, which require at least two stops.
+        let stops = match &cbor {
+            GradientCbor::Linear { stops, .. }
+            | GradientCbor::Radial { stops, .. }
+            | GradientCbor::Conic { stops, .. } => stops.len(),
+        };
+        if stops < 2 {
+            return Err(format!("Gradient needs at least two stops, got {stops}"));
+        }
+
+        Ok(match cbor {
+            GradientCbor::Linear {
+                stops,
+                angle,
+                space,
+            } => Gradient::linear(stops, angle, space),
+            GradientCbor::Radial {
+                stops,
+                center,
+                radius,
+                focal_center,
+                focal_radius,
+                space,
+            } => Gradient::radial(stops, center, radius, focal_center, focal_radius, space),
+            GradientCbor::Conic {
+                stops,
+                angle,
+                center,
+                space,
+            } => Gradient::conic(stops, angle, center, space),
+        })
+    }
+}
+
+/// A gradient whose stop colors are already converted into its color space,
+/// see [`Gradient::prepared`].
+#[derive(Clone, Debug)]
+pub struct PreparedGradient {
+    stops: Vec<Stop>,
+    space: ColorSpace,
+}
+
+impl PreparedGradient {
+    /// Samples the gradient at the given offset, like [`Gradient::sample`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Luma,
+    /// );
+    /// assert_eq!(gradient.prepared().sample(Ratio::new(1.0)), color::WHITE);
+    /// ```
+    pub fn sample(&self, offset: Ratio) -> Color {
+        self.sample_eased(offset, Easing::Linear)
+    }
+
+    /// Samples the gradient at the given offset with an easing curve, like
+    /// [`Gradient::sample_eased`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Color, ColorSpace, Easing, Gradient, Luma, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::BLACK, Ratio::new(0.0)),
+    ///         Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Luma,
+    /// );
+    /// assert_eq!(
+    ///     gradient.prepared().sample_eased(Ratio::new(0.5), Easing::EaseIn),
+    ///     Color::Luma(Luma::new(Ratio::new(0.25), Ratio::new(1.0)))
+    /// );
+    /// ```
+    pub fn sample_eased(&self, offset: Ratio, easing: Easing) -> Color {
+        sample_stops(&self.stops, self.space, offset, easing)
+    }
+}
+
+/// A lookup table of precomputed gradient colors, see [`Gradient::build_lut`].
+#[derive(Clone, Debug)]
+pub struct GradientLut {
+    colors: Vec<Color>,
+}
+
+impl GradientLut {
+    /// Returns the entry nearest to `offset`.
+    ///
+    /// Offsets outside of `[0, 1]` are clamped to the end entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear(
+    ///     vec![
+    ///         Stop::new(color::RED, Ratio::new(0.0)),
+    ///         Stop::new(color::BLUE, Ratio::new(1.0)),
+    ///     ],
+    ///     Angle::new(0.0),
+    ///     ColorSpace::Rgb,
+    /// );
+    /// let lut = gradient.build_lut(16);
+    /// assert_eq!(lut.sample(Ratio::new(-1.0)), color::RED);
+    /// ```
+    pub fn sample(&self, offset: Ratio) -> Color {
+        let last = self.colors.len() - 1;
+        let index = (offset.ratio.clamp(0.0, 1.0) * last as f64).round() as usize;
+
+        self.colors[index.min(last)].clone()
+    }
+
+    /// Returns all precomputed colors, evenly spaced from offset `0` to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient};
+    ///
+    /// let lut = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab).build_lut(0);
+    /// assert_eq!(lut.colors().len(), 1);
+    /// ```
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
+
+/// An easing curve reshaping the transition between two gradient stops.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Easing {
+    /// A constant rate of change.
+    #[default]
+    Linear,
+    /// Starts slowly and accelerates (quadratic).
+    EaseIn,
+    /// Starts quickly and decelerates (quadratic).
+    EaseOut,
+    /// Starts and ends slowly (cubic Hermite).
+    Smoothstep,
+}
+
+impl Easing {
+    /// Applies the easing curve to a local interpolation parameter in `[0, 1]`.
     ///
     /// # Examples
     /// ```
-    /// use typwire::{Center, Gradient, Ratio};
+    /// use typwire::Easing;
     ///
-    /// let builder = Gradient::conic_builder();
+    /// assert_eq!(Easing::Linear.apply(0.5), 0.5);
+    /// assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
     /// ```
-    pub fn conic_builder() -> ConicGradientBuilder {
-        ConicGradientBuilder {
-            stops: vec![],
-            angle: None,
-            center: None,
-            space: ColorSpace::default(),
+    pub const fn apply(self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum ColorSpace {
     Luma,
@@ -177,6 +1481,22 @@ pub enum ColorSpace {
     Hsv,
 }
 
+impl ColorSpace {
+    /// Returns the kebab-case name of the space, as used on the wire.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            ColorSpace::Luma => "luma",
+            ColorSpace::Oklab => "oklab",
+            ColorSpace::Oklch => "oklch",
+            ColorSpace::LinearRgb => "linear-rgb",
+            ColorSpace::Rgb => "rgb",
+            ColorSpace::Cmyk => "cmyk",
+            ColorSpace::Hsl => "hsl",
+            ColorSpace::Hsv => "hsv",
+        }
+    }
+}
+
 /// A builder for creating linear gradients.
 ///
 /// # Examples
@@ -257,6 +1577,9 @@ impl LinearGradientBuilder {
 
     /// Builds the linear gradient.
     ///
+    /// The stops are stably sorted by offset, so they may be added in any
+    /// order. Stops at the same offset keep their order and form a hard stop.
+    ///
     /// # Errors
     /// Returns a `GradientBuilderError` if required fields are missing.
     ///
@@ -274,11 +1597,12 @@ impl LinearGradientBuilder {
     ///     .unwrap();
     /// ```
     pub fn build(self) -> Result<Gradient, GradientBuilderError> {
-        let stops = if self.stops.len() >= 2 {
+        let mut stops = if self.stops.len() >= 2 {
             self.stops
         } else {
             return Err(GradientBuilderError::MissingField("stops"));
         };
+        stops.sort_by(Stop::cmp_offset);
 
         let Some(angle) = self.angle else {
             return Err(GradientBuilderError::MissingField("angle"));
@@ -288,6 +1612,46 @@ impl LinearGradientBuilder {
     }
 }
 
+impl TryFrom<Gradient> for LinearGradientBuilder {
+    type Error = GradientBuilderError;
+
+    /// Turns a linear gradient back into a pre-filled builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Gradient, LinearGradientBuilder, Ratio, Stop, color};
+    ///
+    /// let gradient = Gradient::linear_builder()
+    ///     .stop(Stop::new(color::BLACK, Ratio::new(0.0)))
+    ///     .stop(Stop::new(color::WHITE, Ratio::new(1.0)))
+    ///     .angle(Angle::new(0.0))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let rebuilt = LinearGradientBuilder::try_from(gradient)
+    ///     .unwrap()
+    ///     .angle(Angle::new(1.0))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    fn try_from(value: Gradient) -> Result<Self, Self::Error> {
+        let Gradient::Linear {
+            stops,
+            angle,
+            space,
+        } = value
+        else {
+            return Err(GradientBuilderError::KindMismatch("linear"));
+        };
+
+        Ok(Self {
+            stops,
+            angle: Some(angle),
+            space,
+        })
+    }
+}
+
 /// A builder for creating radial gradients.
 ///
 /// # Examples
@@ -417,6 +1781,9 @@ impl RadialGradientBuilder {
 
     /// Builds the radial gradient.
     ///
+    /// The stops are stably sorted by offset, so they may be added in any
+    /// order. Stops at the same offset keep their order and form a hard stop.
+    ///
     /// # Errors
     /// Returns a `GradientBuilderError` if required fields are missing.
     ///
@@ -437,11 +1804,12 @@ impl RadialGradientBuilder {
     ///     .unwrap();
     /// ```
     pub fn build(self) -> Result<Gradient, GradientBuilderError> {
-        let stops = if self.stops.len() >= 2 {
+        let mut stops = if self.stops.len() >= 2 {
             self.stops
         } else {
             return Err(GradientBuilderError::MissingField("stops"));
         };
+        stops.sort_by(Stop::cmp_offset);
 
         let Some(center) = self.center else {
             return Err(GradientBuilderError::MissingField("center"));
@@ -470,6 +1838,42 @@ impl RadialGradientBuilder {
     }
 }
 
+impl TryFrom<Gradient> for RadialGradientBuilder {
+    type Error = GradientBuilderError;
+
+    /// Turns a radial gradient back into a pre-filled builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, ColorSpace, Gradient, RadialGradientBuilder};
+    ///
+    /// let linear = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+    /// assert!(RadialGradientBuilder::try_from(linear).is_err());
+    /// ```
+    fn try_from(value: Gradient) -> Result<Self, Self::Error> {
+        let Gradient::Radial {
+            stops,
+            center,
+            radius,
+            focal_center,
+            focal_radius,
+            space,
+        } = value
+        else {
+            return Err(GradientBuilderError::KindMismatch("radial"));
+        };
+
+        Ok(Self {
+            stops,
+            center: Some(center),
+            radius: Some(radius),
+            focal_center: Some(focal_center),
+            focal_radius: Some(focal_radius),
+            space,
+        })
+    }
+}
+
 /// A builder for creating conic gradients.
 ///
 /// # Examples
@@ -566,6 +1970,9 @@ impl ConicGradientBuilder {
 
     /// Builds the conic gradient.
     ///
+    /// The stops are stably sorted by offset, so they may be added in any
+    /// order. Stops at the same offset keep their order and form a hard stop.
+    ///
     /// # Errors
     /// Returns a `GradientBuilderError` if required fields are missing.
     ///
@@ -584,11 +1991,12 @@ impl ConicGradientBuilder {
     ///     .unwrap();
     /// ```
     pub fn build(self) -> Result<Gradient, GradientBuilderError> {
-        let stops = if self.stops.len() >= 2 {
+        let mut stops = if self.stops.len() >= 2 {
             self.stops
         } else {
             return Err(GradientBuilderError::MissingField("stops"));
         };
+        stops.sort_by(Stop::cmp_offset);
 
         let Some(angle) = self.angle else {
             return Err(GradientBuilderError::MissingField("angle"));
@@ -602,9 +2010,876 @@ impl ConicGradientBuilder {
     }
 }
 
+impl TryFrom<Gradient> for ConicGradientBuilder {
+    type Error = GradientBuilderError;
+
+    /// Turns a conic gradient back into a pre-filled builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use typwire::{Angle, Center, ColorSpace, ConicGradientBuilder, Gradient, Ratio};
+    ///
+    /// let conic = Gradient::conic(
+    ///     vec![],
+    ///     Angle::new(0.0),
+    ///     Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+    ///     ColorSpace::Oklab,
+    /// );
+    /// let builder = ConicGradientBuilder::try_from(conic).unwrap();
+    /// ```
+    fn try_from(value: Gradient) -> Result<Self, Self::Error> {
+        let Gradient::Conic {
+            stops,
+            angle,
+            center,
+            space,
+        } = value
+        else {
+            return Err(GradientBuilderError::KindMismatch("conic"));
+        };
+
+        Ok(Self {
+            stops,
+            angle: Some(angle),
+            center: Some(center),
+            space,
+        })
+    }
+}
+
 /// Represents errors that can occur while building a gradient.
+///
+/// New variants may be added in the future, so matches must include a
+/// wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum GradientBuilderError {
     #[error("builder missing required field: {0}")]
     MissingField(&'static str),
+    #[error("gradient is not a {0} gradient")]
+    KindMismatch(&'static str),
+    #[error("focal circle must lie within the main circle")]
+    InvalidFocal,
+    #[error("stop {0} has an offset outside of [0, 1]")]
+    InvalidOffset(usize),
+    #[error("stop {0} is not in ascending offset order")]
+    UnorderedStops(usize),
+}
+
+impl From<GradientBuilderError> for String {
+    fn from(error: GradientBuilderError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Removes duplicate gradients from `list`, keeping the first occurrence of each.
+///
+/// Gradients are compared with their [`PartialEq`] implementation, so only
+/// gradients with the same stops in the same order are duplicates.
+///
+/// # Examples
+/// ```
+/// use typwire::{Angle, ColorSpace, Gradient, Ratio, Stop, color, dedup_gradients};
+///
+/// let black = Stop::new(color::BLACK, Ratio::new(0.0));
+/// let white = Stop::new(color::WHITE, Ratio::new(1.0));
+/// let mut list = vec![
+///     Gradient::linear(vec![black.clone(), white.clone()], Angle::new(0.0), ColorSpace::Oklab),
+///     Gradient::linear(vec![black, white], Angle::new(0.0), ColorSpace::Oklab),
+/// ];
+/// dedup_gradients(&mut list);
+/// assert_eq!(list.len(), 1);
+/// ```
+pub fn dedup_gradients(list: &mut Vec<Gradient>) {
+    let mut unique: Vec<Gradient> = Vec::with_capacity(list.len());
+    for gradient in list.drain(..) {
+        if !unique.contains(&gradient) {
+            unique.push(gradient);
+        }
+    }
+
+    *list = unique;
+}
+
+/// Samples stops sorted by ascending offset, see [`Gradient::sample_eased`].
+fn sample_stops(stops: &[Stop], space: ColorSpace, offset: Ratio, easing: Easing) -> Color {
+    match find_span(stops, |stop| stop.offset.ratio, offset.ratio) {
+        None => Color::Luma(Luma::new(Ratio::new(0.0), Ratio::new(0.0))).to_space(space),
+        Some(Span::At(stop)) => stop.color.to_space(space),
+        // Interpolate with premultiplied alpha, so that a transparent stop fades
+        // its neighbor out instead of tinting it with its own color.
+        Some(Span::Between(from, to, t)) => {
+            from.color
+                .mix_premultiplied(&to.color, Ratio::new(easing.apply(t)), space)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+    use crate::{FromBytes as _, ToBytes as _, color};
+
+    fn radial(focal_center: Center) -> Gradient {
+        Gradient::radial_builder()
+            .stop(Stop::new(color::BLACK, Ratio::new(0.0)))
+            .stop(Stop::new(color::WHITE, Ratio::new(1.0)))
+            .center(Center::new(Ratio::new(0.5), Ratio::new(0.5)))
+            .radius(Ratio::new(0.5))
+            .focal_center(focal_center)
+            .focal_radius(Ratio::new(0.1))
+            .build()
+            .unwrap()
+    }
+
+    fn roundtrip(gradient: &Gradient) -> (Gradient, Value) {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(gradient, &mut bytes).unwrap();
+
+        (
+            ciborium::from_reader(bytes.as_slice()).unwrap(),
+            ciborium::from_reader(bytes.as_slice()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn default_space_roundtrip() {
+        let center = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+        let gradients = [
+            Gradient::linear_builder()
+                .stop(Stop::new(color::BLACK, Ratio::new(0.0)))
+                .stop(Stop::new(color::WHITE, Ratio::new(1.0)))
+                .angle(Angle::new(0.0))
+                .build()
+                .unwrap(),
+            radial(center.clone()),
+            Gradient::conic_builder()
+                .stop(Stop::new(color::BLACK, Ratio::new(0.0)))
+                .stop(Stop::new(color::WHITE, Ratio::new(1.0)))
+                .angle(Angle::new(0.0))
+                .center(center)
+                .build()
+                .unwrap(),
+        ];
+
+        for gradient in gradients {
+            let (decoded, value) = roundtrip(&gradient);
+
+            let Value::Map(entries) = value else {
+                panic!("expected a map");
+            };
+            let space = entries
+                .iter()
+                .find(|(key, _)| key.as_text() == Some("space"))
+                .and_then(|(_, value)| value.as_text());
+            assert_eq!(space, Some("oklab"));
+
+            let (Gradient::Linear { space, .. }
+            | Gradient::Radial { space, .. }
+            | Gradient::Conic { space, .. }) = decoded;
+            assert_eq!(space, ColorSpace::Oklab);
+        }
+    }
+
+    #[test]
+    fn deserialize_too_few_stops() {
+        let gradient = Gradient::linear(
+            vec![Stop::new(color::BLACK, Ratio::new(0.0))],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+        let bytes = gradient.to_bytes().unwrap();
+
+        let error = Gradient::from_bytes(&bytes).unwrap_err();
+        assert!(error.contains("Gradient needs at least two stops, got 1"));
+        assert!(gradient.validate().is_err());
+    }
+
+    #[test]
+    fn stop_angles() {
+        let gradient = Gradient::conic(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.0)),
+                Stop::new(color::WHITE, Ratio::new(0.25)),
+                Stop::new(color::BLACK, Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+            ColorSpace::Oklab,
+        );
+
+        assert_eq!(
+            gradient.stop_angles(),
+            Some(vec![
+                Angle::new(0.0),
+                Angle::new(std::f64::consts::FRAC_PI_2),
+                Angle::new(0.0),
+            ])
+        );
+        assert_eq!(
+            radial(Center::new(Ratio::new(0.5), Ratio::new(0.5))).stop_angles(),
+            None
+        );
+    }
+
+    #[test]
+    fn perceptual() {
+        let gradient = Gradient::perceptual(color::RED, color::BLUE, 3);
+        let stops = gradient.stops();
+
+        let offsets: Vec<f64> = stops.iter().map(|stop| stop.offset.ratio).collect();
+        assert_eq!(offsets, [0.0, 0.5, 1.0]);
+
+        // Red (~29°) and blue (~254°) meet across 0°, not through green.
+        let (red, blue) = (color::RED.to_oklch(), color::BLUE.to_oklch());
+        let middle = stops[1].color.to_oklch();
+        assert!(middle.hue.deg() < red.hue.deg() || middle.hue.deg() > blue.hue.deg());
+        assert!(
+            (middle.lightness.ratio - (red.lightness.ratio + blue.lightness.ratio) / 2.0).abs()
+                < 1e-9
+        );
+
+        assert_eq!(
+            Gradient::perceptual(color::RED, color::BLUE, 0)
+                .stops()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn rainbow() {
+        let gradient = Gradient::rainbow(ColorSpace::Oklch, 5);
+
+        let hues: Vec<f64> = gradient
+            .stops()
+            .iter()
+            .map(|stop| stop.color.to_oklch().hue.deg())
+            .collect();
+        for (hue, expected) in hues.iter().zip([0.0, 90.0, 180.0, 270.0, 0.0]) {
+            assert!((hue - expected).abs() < 1e-9);
+        }
+        assert_eq!(
+            gradient.sample(Ratio::new(0.0)),
+            gradient.sample(Ratio::new(1.0))
+        );
+        assert_eq!(Gradient::rainbow(ColorSpace::Oklab, 0).stops().len(), 2);
+    }
+
+    #[test]
+    fn lerp_radial() {
+        let from = radial(Center::new(Ratio::new(0.5), Ratio::new(0.5)));
+        let to = radial(Center::new(Ratio::new(0.7), Ratio::new(0.5)));
+
+        let Gradient::Radial {
+            stops,
+            focal_center,
+            ..
+        } = from.lerp(&to, 0.5).unwrap()
+        else {
+            panic!("expected a radial gradient");
+        };
+        assert!((focal_center.x.ratio - 0.6).abs() < 1e-9);
+        assert_eq!(stops[1].offset, Ratio::new(1.0));
+    }
+
+    #[test]
+    fn lerp_mismatch() {
+        let stops = || {
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.0)),
+                Stop::new(color::WHITE, Ratio::new(1.0)),
+            ]
+        };
+        let linear = Gradient::linear(stops(), Angle::new(0.0), ColorSpace::Oklab);
+        let radial = radial(Center::new(Ratio::new(0.5), Ratio::new(0.5)));
+        assert!(linear.lerp(&radial, 0.5).is_err());
+
+        let mut more = stops();
+        more.push(Stop::new(color::RED, Ratio::new(1.0)));
+        let longer = Gradient::linear(more, Angle::new(0.0), ColorSpace::Oklab);
+        assert_eq!(
+            linear.lerp(&longer, 0.5).unwrap_err(),
+            "Cannot interpolate between gradients with 2 and 3 stops"
+        );
+    }
+
+    #[test]
+    fn to_rgba_strip() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::RED, Ratio::new(0.0)),
+                Stop::new(color::BLUE, Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+        let image = gradient.to_rgba_strip(3, 2);
+
+        assert_eq!(image.len(), 24);
+        assert_eq!(image[..4], [255, 65, 54, 255]);
+        assert_eq!(image[20..], [0, 116, 217, 255]);
+        assert_eq!(image[..12], image[12..]);
+    }
+
+    #[test]
+    fn sample_premultiplied() {
+        let rgb = |r: f64, b: f64, alpha: f64| {
+            Color::Rgb(Rgb::new(
+                Ratio::new(r),
+                Ratio::new(0.0),
+                Ratio::new(b),
+                Ratio::new(alpha),
+            ))
+        };
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(rgb(1.0, 0.0, 0.0), Ratio::new(0.0)),
+                Stop::new(rgb(0.0, 1.0, 1.0), Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Rgb,
+        );
+
+        // A naive interpolation would yield a muddy purple `rgb(0.5, 0, 0.5, 0.5)`.
+        assert_eq!(gradient.sample(Ratio::new(0.5)), rgb(0.0, 1.0, 0.5));
+        assert_eq!(gradient.sample(Ratio::new(0.0)), rgb(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn sample_into() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::RED, Ratio::new(0.0)),
+                Stop::new(color::LIME, Ratio::new(0.5)),
+                Stop::new(color::BLUE, Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklch,
+        );
+
+        let mut rgb = color::BLACK.to_rgb();
+        for offset in [-0.5, 0.0, 0.25, 0.5, 0.9, 1.0, 2.0] {
+            let offset = Ratio::new(offset);
+            gradient.sample_into(offset, &mut rgb);
+            assert_eq!(rgb, gradient.sample(offset).to_rgb());
+        }
+    }
+
+    #[test]
+    fn prepared() {
+        let stops = vec![
+            Stop::new(color::RED, Ratio::new(0.0)),
+            Stop::new(color::TEAL, Ratio::new(0.3)),
+            Stop::new(color::BLUE.to_space(ColorSpace::Hsv), Ratio::new(0.3)),
+            Stop::new(color::YELLOW, Ratio::new(1.0)),
+        ];
+
+        for space in [
+            ColorSpace::Luma,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+            ColorSpace::LinearRgb,
+            ColorSpace::Rgb,
+            ColorSpace::Cmyk,
+            ColorSpace::Hsl,
+            ColorSpace::Hsv,
+        ] {
+            let gradient = Gradient::linear(stops.clone(), Angle::new(0.0), space);
+            let prepared = gradient.prepared();
+
+            for i in -10..=1010 {
+                let offset = Ratio::new(i as f64 / 1000.0);
+                assert_eq!(prepared.sample(offset), gradient.sample(offset));
+            }
+        }
+    }
+
+    #[test]
+    fn lut() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.0)),
+                Stop::new(color::WHITE, Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Luma,
+        );
+        let lut = gradient.build_lut(5);
+
+        assert_eq!(lut.colors().len(), 5);
+        assert_eq!(
+            lut.sample(Ratio::new(0.3)),
+            gradient.sample(Ratio::new(0.25))
+        );
+        assert_eq!(lut.sample(Ratio::new(2.0)), color::WHITE);
+        assert_eq!(lut.sample(Ratio::new(f64::NAN)), color::BLACK);
+    }
+
+    #[test]
+    fn from_css_linear() {
+        let gradient =
+            Gradient::from_css("linear-gradient(180deg, #ff0000 0%, navy 100%)").unwrap();
+
+        let Gradient::Linear {
+            stops,
+            angle,
+            space,
+        } = gradient
+        else {
+            panic!("expected a linear gradient");
+        };
+        assert_eq!(stops.len(), 2);
+        assert_eq!(stops[0].color, Color::from_hex("#f00").unwrap());
+        assert_eq!(stops[0].offset, Ratio::new(0.0));
+        assert_eq!(stops[1].color, color::NAVY);
+        assert_eq!(stops[1].offset, Ratio::new(1.0));
+        assert!((angle.deg() - 90.0).abs() < 1e-9);
+        assert_eq!(space, ColorSpace::Rgb);
+    }
+
+    #[test]
+    fn from_css_distributes_offsets() {
+        let gradient =
+            Gradient::from_css("conic-gradient(from 90deg at 25% 75%, red, lime, blue)").unwrap();
+
+        let offsets: Vec<f64> = gradient
+            .stops()
+            .iter()
+            .map(|stop| stop.offset.ratio)
+            .collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        assert!(matches!(
+            gradient,
+            Gradient::Conic { center, .. } if center == Center::new(Ratio::new(0.25), Ratio::new(0.75))
+        ));
+    }
+
+    #[test]
+    fn from_css_radial() {
+        let radius = |css: &str| match Gradient::from_css(css).unwrap() {
+            Gradient::Radial { radius, .. } => radius.ratio,
+            _ => panic!("expected a radial gradient"),
+        };
+
+        assert!((radius("radial-gradient(red, blue)") - 0.5f64.hypot(0.5)).abs() < 1e-12);
+        assert_eq!(
+            radius("radial-gradient(closest-side at 25% 50%, red, blue)"),
+            0.25
+        );
+        assert_eq!(
+            radius("radial-gradient(ellipse farthest-side, red, blue)"),
+            0.5
+        );
+        assert_eq!(radius("radial-gradient(40% at 50% 50%, red, blue)"), 0.4);
+
+        assert!(Gradient::from_css("radial-gradient(circle, red, blue)").is_err());
+        assert!(Gradient::from_css("radial-gradient(10% 20%, red, blue)").is_err());
+    }
+
+    #[test]
+    fn from_css_color_functions() {
+        let gradient = Gradient::from_css(
+            "linear-gradient(rgb(255, 65, 54), oklch(70% 0.1 120deg / 50%) 40%, blue)",
+        )
+        .unwrap();
+
+        assert_eq!(gradient.stops()[0].color, color::RED);
+        assert!(matches!(gradient.stops()[1].color, Color::Oklch(_)));
+        assert_eq!(gradient.stops()[1].offset, Ratio::new(0.4));
+    }
+
+    #[test]
+    fn from_css_unsupported() {
+        assert!(Gradient::from_css("repeating-linear-gradient(red, blue)").is_err());
+        assert!(Gradient::from_css("linear-gradient(to nowhere, red, blue)").is_err());
+        assert!(Gradient::from_css("linear-gradient(red)").is_err());
+
+        let error = Gradient::from_css("linear-gradient(red, blue 150%)").unwrap_err();
+        assert_eq!(error, "CSS stop offset 150% is outside of [0%, 100%]");
+
+        let error = Gradient::from_css("linear-gradient(nosuch, blue)").unwrap_err();
+        assert!(error.starts_with("Invalid color: nosuch"), "{error}");
+    }
+
+    #[test]
+    fn validate_accumulates_problems() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.5)),
+                Stop::new(
+                    Color::Luma(Luma::new(Ratio::new(2.0), Ratio::new(1.0))),
+                    Ratio::new(0.25),
+                ),
+                Stop::new(color::WHITE, Ratio::new(1.5)),
+            ],
+            Angle::new(f64::NAN),
+            ColorSpace::Oklab,
+        );
+
+        assert_eq!(
+            gradient.validate().unwrap_err(),
+            vec![
+                "stops[1]: color: lightness: 2 is outside of [0, 1]",
+                "stop 1 is not in ascending offset order",
+                "stops[2]: offset: 1.5 is outside of [0, 1]",
+                "angle: NaN is not finite",
+            ]
+        );
+        assert!(
+            radial(Center::new(Ratio::new(0.5), Ratio::new(0.5)))
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn error_into_string() {
+        fn build() -> Result<Gradient, String> {
+            Ok(Gradient::linear_builder().build()?)
+        }
+
+        assert_eq!(
+            build().unwrap_err(),
+            "builder missing required field: stops"
+        );
+        assert_eq!(
+            String::from(GradientBuilderError::InvalidFocal),
+            "focal circle must lie within the main circle"
+        );
+    }
+
+    #[test]
+    fn validate_radial() {
+        let gradient = radial(Center::new(Ratio::new(0.6), Ratio::new(0.4)));
+
+        assert!(gradient.check().is_ok());
+    }
+
+    #[test]
+    fn validate_radial_focal_out_of_bounds() {
+        let gradient = radial(Center::new(Ratio::new(1.2), Ratio::new(0.5)));
+
+        assert!(matches!(
+            gradient.check(),
+            Err(GradientBuilderError::InvalidFocal)
+        ));
+    }
+
+    #[test]
+    fn as_conic_and_linear() {
+        let stops = vec![
+            Stop::new(color::RED, Ratio::new(0.0)),
+            Stop::new(color::GREEN, Ratio::new(0.4)),
+            Stop::new(color::BLUE, Ratio::new(1.0)),
+        ];
+        let linear = Gradient::linear(stops.clone(), Angle::new(0.5), ColorSpace::Hsl);
+        let center = Center::new(Ratio::new(0.2), Ratio::new(0.8));
+
+        let conic = linear.as_conic(center.clone());
+        assert_eq!(conic.stops(), stops);
+        assert_eq!(conic.space(), ColorSpace::Hsl);
+        assert_eq!(conic.center(), Some(&center));
+        assert_eq!(conic.as_linear(Angle::new(0.5)), linear);
+
+        let radial = radial(center.clone());
+        let Gradient::Conic { angle, .. } = radial.as_conic(center) else {
+            panic!("expected a conic gradient");
+        };
+        assert_eq!(angle, Angle::new(0.0));
+    }
+
+    #[test]
+    fn centers() {
+        let center = Center::new(Ratio::new(0.5), Ratio::new(0.5));
+        let focal_center = Center::new(Ratio::new(0.4), Ratio::new(0.5));
+
+        let gradient = radial(focal_center.clone());
+        assert_eq!(gradient.center(), Some(&center));
+        assert_eq!(gradient.focal_center(), Some(&focal_center));
+
+        let gradient = Gradient::conic(vec![], Angle::new(0.0), center.clone(), ColorSpace::Oklab);
+        assert_eq!(gradient.center(), Some(&center));
+        assert_eq!(gradient.focal_center(), None);
+
+        let gradient = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+        assert_eq!(gradient.center(), None);
+    }
+
+    #[test]
+    fn direction() {
+        let down = Gradient::linear(vec![], Angle::new(FRAC_PI_2), ColorSpace::Oklab);
+        let (dx, dy) = down.direction().unwrap();
+        assert!(dx.abs() < 1e-12);
+        assert_eq!(dy, 1.0);
+
+        let left = Gradient::linear(vec![], Angle::new(PI), ColorSpace::Oklab);
+        let (dx, dy) = left.direction().unwrap();
+        assert_eq!(dx, -1.0);
+        assert!(dy.abs() < 1e-12);
+
+        assert_eq!(
+            radial(Center::new(Ratio::new(0.5), Ratio::new(0.5))).direction(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_opacity() {
+        let stops = vec![
+            Stop::new(color::RED, Ratio::new(0.0)),
+            Stop::new(color::BLACK.with_alpha(Ratio::new(0.5)), Ratio::new(1.0)),
+        ];
+        let gradient = Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklab);
+
+        let faded = gradient.with_opacity(Ratio::new(0.5));
+        let alphas: Vec<f64> = faded
+            .stops()
+            .iter()
+            .map(|stop| stop.color.alpha().ratio)
+            .collect();
+        assert_eq!(alphas, [0.5, 0.25]);
+        assert_eq!(faded.stops()[0].color.to_rgb().r, Ratio::new(1.0));
+    }
+
+    #[test]
+    fn scale_alpha_cmyk() {
+        let cmyk = Color::Cmyk(crate::Cmyk::new(
+            Ratio::new(0.0),
+            Ratio::new(0.0),
+            Ratio::new(0.0),
+            Ratio::new(1.0),
+        ));
+        let gradient = Gradient::linear(
+            vec![Stop::new(cmyk.clone(), Ratio::new(0.0))],
+            Angle::new(0.0),
+            ColorSpace::Cmyk,
+        );
+
+        assert_eq!(gradient.scale_alpha(Ratio::new(1.0)), gradient);
+
+        let faded = gradient.scale_alpha(Ratio::new(0.5));
+        assert_eq!(
+            faded.stops()[0].color,
+            Color::Rgb(cmyk.to_rgb()).with_alpha(Ratio::new(0.5))
+        );
+    }
+
+    #[test]
+    fn builders_sort_stops() {
+        let offsets = |gradient: Gradient| -> Vec<f64> {
+            gradient
+                .stops()
+                .iter()
+                .map(|stop| stop.offset.ratio)
+                .collect()
+        };
+        let stops = [
+            Stop::new(color::WHITE, Ratio::new(1.0)),
+            Stop::new(color::RED, Ratio::new(0.5)),
+            Stop::new(color::BLACK, Ratio::new(0.0)),
+            Stop::new(color::BLUE, Ratio::new(0.5)),
+        ];
+
+        let linear = stops
+            .iter()
+            .fold(Gradient::linear_builder(), |builder, stop| {
+                builder.stop(stop.clone())
+            })
+            .angle(Angle::new(0.0))
+            .build()
+            .unwrap();
+        assert_eq!(linear.stops()[1].color, color::RED);
+        assert_eq!(linear.stops()[2].color, color::BLUE);
+        assert_eq!(offsets(linear), [0.0, 0.5, 0.5, 1.0]);
+
+        let conic = Gradient::conic_builder()
+            .stops(stops.to_vec())
+            .angle(Angle::new(0.0))
+            .center(Center::new(Ratio::new(0.5), Ratio::new(0.5)))
+            .build()
+            .unwrap();
+        assert_eq!(offsets(conic), [0.0, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn stops_in_space() {
+        let stops = vec![
+            Stop::new(color::BLACK, Ratio::new(0.0)),
+            Stop::new(color::RED, Ratio::new(0.75)),
+        ];
+        let gradient = Gradient::linear(stops.clone(), Angle::new(0.0), ColorSpace::Oklab);
+
+        let converted = gradient.stops_in_space(ColorSpace::Rgb);
+        assert_eq!(converted[0].color, Color::Rgb(color::BLACK.to_rgb()));
+        assert_eq!(converted[1].color, color::RED);
+        assert_eq!(converted[1].offset, Ratio::new(0.75));
+        assert_eq!(gradient.stops(), stops);
+    }
+
+    #[test]
+    fn debug() {
+        let stops = vec![
+            Stop::new(color::BLACK, Ratio::new(0.0)),
+            Stop::new(color::WHITE, Ratio::new(1.0)),
+        ];
+        let linear = Gradient::linear(stops.clone(), Angle::new(PI), ColorSpace::Oklab);
+        assert_eq!(
+            format!("{linear:?}"),
+            "linear[oklab] 180deg {black@0, white@1}"
+        );
+
+        let conic = Gradient::conic(
+            stops,
+            Angle::new(0.0),
+            Center::new(Ratio::new(0.5), Ratio::new(0.25)),
+            ColorSpace::LinearRgb,
+        );
+        assert_eq!(
+            format!("{conic:?}"),
+            "conic[linear-rgb] 0deg at (0.5, 0.25) {black@0, white@1}"
+        );
+
+        let radial = radial(Center::new(Ratio::new(0.5), Ratio::new(0.5)));
+        assert_eq!(
+            format!("{radial:?}"),
+            "radial[oklab] at (0.5, 0.5) r=0.5 focal (0.5, 0.5) r=0.1 {black@0, white@1}"
+        );
+        assert!(format!("{radial:#?}").starts_with("Radial {\n    stops: ["));
+    }
+
+    #[test]
+    fn rotate() {
+        let turn = Angle::new(std::f64::consts::TAU);
+        let stops = vec![
+            Stop::new(color::BLACK, Ratio::new(0.0)),
+            Stop::new(color::WHITE, Ratio::new(1.0)),
+        ];
+
+        let linear = Gradient::linear(stops.clone(), Angle::new(1.0), ColorSpace::Oklab);
+        let Gradient::Linear { angle, .. } = linear.rotate(turn) else {
+            panic!("expected a linear gradient");
+        };
+        assert!((angle.rad() - 1.0).abs() < 1e-12);
+
+        let conic = Gradient::conic(
+            stops.clone(),
+            Angle::new(-1.0),
+            Center::new(Ratio::new(0.5), Ratio::new(0.5)),
+            ColorSpace::Oklab,
+        );
+        let Gradient::Conic { angle, .. } = conic.rotate(Angle::new(0.5)) else {
+            panic!("expected a conic gradient");
+        };
+        assert!((angle.rad() - (std::f64::consts::TAU - 0.5)).abs() < 1e-12);
+
+        let radial = radial(Center::new(Ratio::new(0.5), Ratio::new(0.5)));
+        assert_eq!(radial.rotate(Angle::new(1.0)), radial);
+    }
+
+    #[test]
+    fn summary_stops() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.0)),
+                Stop::new(color::RED, Ratio::new(0.3)),
+                Stop::new(color::RED, Ratio::new(0.6)),
+                Stop::new(color::WHITE, Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+
+        let most_saturated = gradient.most_saturated_stop().unwrap();
+        assert_eq!(most_saturated.offset, Ratio::new(0.3));
+        assert_eq!(gradient.lightest_stop().unwrap().color, color::WHITE);
+        assert_eq!(gradient.darkest_stop().unwrap().color, color::BLACK);
+
+        let empty = Gradient::linear(vec![], Angle::new(0.0), ColorSpace::Oklab);
+        assert!(empty.most_saturated_stop().is_none());
+    }
+
+    #[test]
+    fn dedup() {
+        let black = Stop::new(color::BLACK, Ratio::new(0.0));
+        let white = Stop::new(color::WHITE, Ratio::new(1.0));
+        let mut list = vec![
+            Gradient::linear(
+                vec![black.clone(), white.clone()],
+                Angle::new(0.0),
+                ColorSpace::Oklab,
+            ),
+            Gradient::linear(
+                vec![black.clone(), white.clone()],
+                Angle::new(0.0),
+                ColorSpace::Rgb,
+            ),
+            Gradient::linear(
+                vec![black.clone(), white.clone()],
+                Angle::new(0.0),
+                ColorSpace::Oklab,
+            ),
+        ];
+
+        dedup_gradients(&mut list);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[1].space(), ColorSpace::Rgb);
+
+        // Listing the stops in another order renders differently.
+        let reversed = Gradient::linear(vec![white, black], Angle::new(0.0), ColorSpace::Oklab);
+        assert_ne!(list[0], reversed);
+        assert_ne!(
+            list[0].sample(Ratio::new(0.25)),
+            reversed.sample(Ratio::new(0.25))
+        );
+    }
+
+    #[test]
+    fn eq_keeps_hard_stop_order() {
+        let stops = vec![
+            Stop::new(color::BLACK, Ratio::new(0.5)),
+            Stop::new(color::WHITE, Ratio::new(0.5)),
+        ];
+        let reversed = stops.iter().rev().cloned().collect();
+
+        assert_ne!(
+            Gradient::linear(stops, Angle::new(0.0), ColorSpace::Oklab),
+            Gradient::linear(reversed, Angle::new(0.0), ColorSpace::Oklab)
+        );
+    }
+
+    #[test]
+    fn validate_stop_offsets() {
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.5)),
+                Stop::new(color::WHITE, Ratio::new(0.2)),
+                Stop::new(color::WHITE, Ratio::new(1.5)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+
+        assert!(matches!(
+            gradient.check(),
+            Err(GradientBuilderError::UnorderedStops(1))
+        ));
+
+        let gradient = Gradient::linear(
+            vec![
+                Stop::new(color::BLACK, Ratio::new(0.0)),
+                Stop::new(color::WHITE, Ratio::new(f64::NAN)),
+                Stop::new(color::WHITE, Ratio::new(1.5)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+
+        assert_eq!(
+            gradient.check().unwrap_err().to_string(),
+            "stop 1 has an offset outside of [0, 1]"
+        );
+    }
 }
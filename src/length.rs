@@ -1,8 +1,10 @@
-use serde::{Deserialize, Serialize};
+use std::iter::Sum;
+use std::ops::Add;
 
-use crate::Radius;
+use serde::{Deserialize, Serialize};
 
-const TYPE_NAME: &str = "length";
+use crate::validate::Problems;
+use crate::{Radius, Ratio, Tagged, Validate};
 
 /// A structure representing a length in points.
 ///
@@ -15,13 +17,17 @@ const TYPE_NAME: &str = "length";
 /// assert_eq!(length.pt(), 72.0);
 /// assert_eq!(length.inches(), 1.0);
 /// ```
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "LengthCbor", into = "LengthCbor")]
 pub struct Length {
     points: f64,
 }
 
 impl Length {
+    /// A length of zero points.
+    pub const ZERO: Length = Length::new(0.0);
+
     /// Creates a new `Length` instance from a given number of points.
     ///
     /// # Arguments
@@ -95,12 +101,250 @@ impl Length {
     pub const fn inches(&self) -> f64 {
         self.points / 72.0
     }
+
+    /// Converts the length to pixels at the given resolution in dots per inch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// let length = Length::new(36.0);
+    /// assert_eq!(length.to_px(300.0), 150.0);
+    /// ```
+    pub const fn to_px(&self, dpi: f64) -> f64 {
+        self.inches() * dpi
+    }
+
+    /// Converts the length to pixels at the CSS resolution of 96 DPI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// let length = Length::new(72.0);
+    /// assert_eq!(length.to_px_96(), 96.0);
+    /// ```
+    pub const fn to_px_96(&self) -> f64 {
+        self.to_px(96.0)
+    }
+
+    /// Creates a new `Length` from pixels at the given resolution in dots per inch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// let length = Length::from_px(150.0, 300.0);
+    /// assert_eq!(length.pt(), 36.0);
+    /// ```
+    pub const fn from_px(px: f64, dpi: f64) -> Length {
+        Length::from_unit(px / dpi, LengthUnit::In)
+    }
+
+    /// Creates a new `Length` from a magnitude in the given unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, LengthUnit};
+    ///
+    /// let length = Length::from_unit(1.0, LengthUnit::In);
+    /// assert_eq!(length.pt(), 72.0);
+    /// ```
+    pub const fn from_unit(value: f64, unit: LengthUnit) -> Self {
+        Self::new(value * unit.points())
+    }
+
+    /// Returns the magnitude of the length expressed in the given unit,
+    /// together with that unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, LengthUnit};
+    ///
+    /// let length = Length::new(72.0);
+    /// assert_eq!(length.into_unit(LengthUnit::Mm), (25.4, LengthUnit::Mm));
+    /// ```
+    pub const fn into_unit(&self, unit: LengthUnit) -> (f64, LengthUnit) {
+        let value = match unit {
+            LengthUnit::Pt => self.pt(),
+            LengthUnit::Mm => self.mm(),
+            LengthUnit::Cm => self.cm(),
+            LengthUnit::In => self.inches(),
+        };
+
+        (value, unit)
+    }
+
+    /// Returns `true` if the length is greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// assert!(Length::new(12.0).is_positive());
+    /// assert!(!Length::ZERO.is_positive());
+    /// ```
+    pub const fn is_positive(&self) -> bool {
+        self.points > 0.0
+    }
+
+    /// Returns `true` if the length is less than zero, e.g. for a negative margin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// assert!(Length::new(-12.0).is_negative());
+    /// assert!(!Length::new(-0.0).is_negative());
+    /// ```
+    pub const fn is_negative(&self) -> bool {
+        self.points < 0.0
+    }
+
+    /// Returns `true` if the length is zero, including negative zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// assert!(Length::ZERO.is_zero());
+    /// assert!(Length::new(-0.0).is_zero());
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.points == 0.0
+    }
+
+    /// Returns the sign of the length: `1.0` if positive, `-1.0` if negative and
+    /// `0.0` if zero. `NaN` lengths return `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// assert_eq!(Length::new(-3.0).signum(), -1.0);
+    /// assert_eq!(Length::new(-0.0).signum(), 0.0);
+    /// ```
+    pub const fn signum(&self) -> f64 {
+        if self.is_zero() {
+            0.0
+        } else {
+            self.points.signum()
+        }
+    }
+
+    /// Returns the magnitude of the length, dropping its direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Length;
+    ///
+    /// assert_eq!(Length::new(-3.0).abs(), Length::new(3.0));
+    /// ```
+    pub const fn abs(&self) -> Length {
+        Length::new(self.points.abs())
+    }
+
+    /// Returns the length scaled by the given ratio, e.g. `50%` of a length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, Ratio};
+    ///
+    /// let length = Length::new(100.0).scaled(Ratio::new(0.25));
+    /// assert_eq!(length.pt(), 25.0);
+    /// ```
+    pub const fn scaled(&self, ratio: Ratio) -> Length {
+        Length::new(self.points * ratio.ratio)
+    }
+}
+
+/// An absolute unit a `Length` can be expressed in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LengthUnit {
+    /// Points (1/72 of an inch).
+    Pt,
+    /// Millimeters.
+    Mm,
+    /// Centimeters.
+    Cm,
+    /// Inches.
+    In,
+}
+
+impl LengthUnit {
+    /// Returns the number of points in one of this unit.
+    const fn points(self) -> f64 {
+        match self {
+            LengthUnit::Pt => 1.0,
+            LengthUnit::Mm => 72.0 / 25.4,
+            LengthUnit::Cm => 720.0 / 25.4,
+            LengthUnit::In => 72.0,
+        }
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, rhs: Length) -> Self::Output {
+        Length::new(self.points + rhs.points)
+    }
+}
+
+impl Sum for Length {
+    fn sum<I: Iterator<Item = Length>>(iter: I) -> Self {
+        iter.fold(Length::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Length> for Length {
+    fn sum<I: Iterator<Item = &'a Length>>(iter: I) -> Self {
+        iter.fold(Length::ZERO, |total, length| {
+            Length::new(total.points + length.points)
+        })
+    }
+}
+
+impl Tagged for Length {
+    const TYPE_NAME: &'static str = "length";
+}
+
+impl Validate for Length {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.check(self.points.is_finite(), || {
+            format!("{} is not finite", self.points)
+        });
+        problems.finish()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct LengthCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Length, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Length>")
+    )]
     typwire_type: String,
+    #[cfg_attr(feature = "fixed", serde(with = "crate::fixed"))]
+    #[cfg_attr(all(feature = "fixed", feature = "schemars"), schemars(with = "i32"))]
     points: f64,
 }
 
@@ -108,7 +352,7 @@ struct LengthCbor {
 impl From<Length> for LengthCbor {
     fn from(value: Length) -> Self {
         Self {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Length::TYPE_NAME.to_string(),
             points: value.points,
         }
     }
@@ -119,18 +363,12 @@ impl TryFrom<LengthCbor> for Length {
     type Error = String;
 
     fn try_from(value: LengthCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for Length: {}",
-                value.typwire_type
-            ));
-        }
-
         Ok(Length::new(value.points))
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 /// An enum representing either a `Length` or a `Radius`.
 ///
@@ -145,3 +383,159 @@ pub enum LengthRadius {
     Length(Length),
     Radius(Radius),
 }
+
+impl LengthRadius {
+    /// Creates a radius from CSS `border-radius` shorthand values.
+    ///
+    /// One value applies to every corner, two values to the top-left and
+    /// bottom-right and then the top-right and bottom-left corners, three values
+    /// to the top-left, the top-right and bottom-left, and the bottom-right
+    /// corners, and four values to each corner clockwise from the top-left.
+    /// If all corners end up equal, the result collapses to a single length.
+    ///
+    /// Values beyond the fourth are ignored, and an empty slice leaves every
+    /// corner unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, LengthRadius};
+    ///
+    /// let small = Length::new(2.0);
+    /// let large = Length::new(8.0);
+    ///
+    /// let LengthRadius::Radius(radius) = LengthRadius::from_shorthand(&[small.clone(), large.clone()]) else {
+    ///     panic!("expected a radius");
+    /// };
+    /// assert_eq!(radius.top_left, Some(small));
+    /// assert_eq!(radius.top_right, Some(large));
+    /// ```
+    pub fn from_shorthand(values: &[Length]) -> LengthRadius {
+        let (top_left, top_right, bottom_right, bottom_left) = match values {
+            [] => return LengthRadius::Radius(Radius::new(None, None, None, None)),
+            [all] => (all, all, all, all),
+            [diagonal, anti_diagonal] => (diagonal, anti_diagonal, diagonal, anti_diagonal),
+            [top_left, anti_diagonal, bottom_right] => {
+                (top_left, anti_diagonal, bottom_right, anti_diagonal)
+            }
+            [top_left, top_right, bottom_right, bottom_left, ..] => {
+                (top_left, top_right, bottom_right, bottom_left)
+            }
+        };
+
+        if [top_right, bottom_right, bottom_left]
+            .iter()
+            .all(|&corner| corner == top_left)
+        {
+            return LengthRadius::Length(top_left.clone());
+        }
+
+        LengthRadius::Radius(Radius::new(
+            Some(top_left.clone()),
+            Some(top_right.clone()),
+            Some(bottom_left.clone()),
+            Some(bottom_right.clone()),
+        ))
+    }
+}
+
+impl Validate for LengthRadius {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        match self {
+            Self::Length(length) => length.validate(),
+            Self::Radius(radius) => radius.validate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corners(radius: LengthRadius) -> [Option<f64>; 4] {
+        let LengthRadius::Radius(radius) = radius else {
+            panic!("expected a radius");
+        };
+        [
+            radius.top_left,
+            radius.top_right,
+            radius.bottom_right,
+            radius.bottom_left,
+        ]
+        .map(|corner| corner.map(|length| length.pt()))
+    }
+
+    #[test]
+    fn from_shorthand() {
+        let lengths = [1.0, 2.0, 3.0, 4.0].map(Length::new);
+
+        assert!(matches!(
+            LengthRadius::from_shorthand(&lengths[..1]),
+            LengthRadius::Length(length) if length == Length::new(1.0)
+        ));
+        assert_eq!(
+            corners(LengthRadius::from_shorthand(&lengths[..2])),
+            [Some(1.0), Some(2.0), Some(1.0), Some(2.0)]
+        );
+        assert_eq!(
+            corners(LengthRadius::from_shorthand(&lengths[..3])),
+            [Some(1.0), Some(2.0), Some(3.0), Some(2.0)]
+        );
+        assert_eq!(
+            corners(LengthRadius::from_shorthand(&lengths)),
+            [Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+        assert!(matches!(
+            LengthRadius::from_shorthand(&[Length::new(5.0), Length::new(5.0)]),
+            LengthRadius::Length(_)
+        ));
+    }
+
+    #[test]
+    fn pixels() {
+        let length = Length::new(18.0);
+        assert_eq!(length.to_px(144.0), 36.0);
+        assert_eq!(length.to_px_96(), 24.0);
+        assert_eq!(Length::from_px(24.0, 96.0), length);
+        assert_eq!(Length::from_px(length.to_px(300.0), 300.0), length);
+    }
+
+    #[test]
+    fn sign() {
+        let cases = [(-5.0, -1.0), (-0.0, 0.0), (0.0, 0.0), (5.0, 1.0)];
+
+        for (points, signum) in cases {
+            let length = Length::new(points);
+            assert_eq!(length.signum(), signum);
+            assert_eq!(length.is_positive(), signum > 0.0);
+            assert_eq!(length.is_negative(), signum < 0.0);
+            assert_eq!(length.is_zero(), signum == 0.0);
+        }
+        assert!(Length::new(f64::NAN).signum().is_nan());
+    }
+
+    #[test]
+    fn abs() {
+        let length = Length::new(-2.5);
+        assert_eq!(length.abs(), Length::new(2.5));
+        assert_eq!(length.abs().signum(), 1.0);
+        assert_eq!(Length::new(2.5).abs(), Length::new(2.5));
+        assert!(Length::new(-0.0).abs().points.is_sign_positive());
+    }
+
+    #[test]
+    fn scaled() {
+        assert_eq!(
+            Length::new(100.0).scaled(Ratio::new(0.5)),
+            Length::new(50.0)
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let lengths = vec![Length::new(12.0), Length::new(24.0), Length::new(36.0)];
+
+        assert_eq!(lengths.iter().sum::<Length>(), Length::new(72.0));
+        assert_eq!(lengths.into_iter().sum::<Length>(), Length::new(72.0));
+    }
+}
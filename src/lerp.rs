@@ -0,0 +1,185 @@
+use crate::{Angle, Center, Color, ColorSpace, Length, Ratio};
+
+/// A trait for values that can be linearly interpolated.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Length, Lerp};
+///
+/// let length = Length::new(10.0).lerp(&Length::new(20.0), 0.25);
+/// assert_eq!(length, Length::new(12.5));
+/// ```
+pub trait Lerp {
+    /// Interpolates between `self` at a `t` of `0` and `other` at a `t` of `1`.
+    ///
+    /// Values of `t` outside of `[0, 1]` extrapolate.
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for Ratio {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Ratio::new(self.ratio + (other.ratio - self.ratio) * t)
+    }
+}
+
+impl Lerp for Length {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Length::new(self.pt() + (other.pt() - self.pt()) * t)
+    }
+}
+
+// Angles are interpolated linearly, without wrapping around.
+impl Lerp for Angle {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Angle::new(self.rad() + (other.rad() - self.rad()) * t)
+    }
+}
+
+impl Lerp for Center {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Center::new(self.x.lerp(&other.x, t), self.y.lerp(&other.y, t))
+    }
+}
+
+// Colors are mixed in Oklab, the default color space of gradients, with
+// premultiplied alpha like gradient samples.
+impl Lerp for Color {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self.mix_premultiplied(other, Ratio::new(t), ColorSpace::Oklab)
+    }
+}
+
+/// Interpolates between values placed at ascending offsets, like sampling a gradient.
+///
+/// Offsets before the first or after the last stop yield the value of that
+/// stop. This generalizes [`Gradient::sample`](crate::Gradient::sample) to any
+/// [`Lerp`] value, e.g. for animating lengths or angles.
+///
+/// # Returns
+///
+/// Returns the interpolated value, or `None` if `stops` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Length, Ratio, interpolate_stops};
+///
+/// let stops = [
+///     (Ratio::new(0.0), Length::new(0.0)),
+///     (Ratio::new(0.5), Length::new(10.0)),
+///     (Ratio::new(1.0), Length::new(0.0)),
+/// ];
+/// assert_eq!(interpolate_stops(&stops, Ratio::new(0.75)), Some(Length::new(5.0)));
+/// ```
+pub fn interpolate_stops<T: Lerp + Clone>(stops: &[(Ratio, T)], at: Ratio) -> Option<T> {
+    Some(
+        match find_span(stops, |(offset, _)| offset.ratio, at.ratio)? {
+            Span::At((_, value)) => value.clone(),
+            Span::Between((_, from), (_, to), t) => from.lerp(to, t),
+        },
+    )
+}
+
+/// The stops surrounding an offset, see [`find_span`].
+pub(crate) enum Span<'a, T> {
+    /// The offset lies before the first or after the last stop.
+    At(&'a T),
+    /// The offset lies between two stops, at the given fraction of the way.
+    Between(&'a T, &'a T, f64),
+}
+
+/// Finds the stops surrounding `at` among stops at ascending offsets.
+///
+/// At a hard stop, where two stops share an offset, the later one wins.
+/// Returns `None` if `stops` is empty.
+pub(crate) fn find_span<T>(
+    stops: &[T],
+    offset: impl Fn(&T) -> f64,
+    at: f64,
+) -> Option<Span<'_, T>> {
+    let (first, last) = (stops.first()?, stops.last()?);
+
+    let index = stops.partition_point(|stop| offset(stop).total_cmp(&at).is_le());
+    if index == 0 {
+        return Some(Span::At(first));
+    }
+    if index == stops.len() {
+        return Some(Span::At(last));
+    }
+
+    let (from, to) = (&stops[index - 1], &stops[index]);
+    let width = offset(to) - offset(from);
+    let t = if width > 0.0 {
+        (at - offset(from)) / width
+    } else {
+        1.0
+    };
+
+    Some(Span::Between(from, to, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Angle, color};
+
+    #[test]
+    fn interpolate_angles() {
+        let stops = [
+            (Ratio::new(0.25), Angle::new(0.0)),
+            (Ratio::new(0.75), Angle::new(2.0)),
+        ];
+
+        assert_eq!(
+            interpolate_stops(&stops, Ratio::new(0.0)),
+            Some(Angle::new(0.0))
+        );
+        assert_eq!(
+            interpolate_stops(&stops, Ratio::new(0.5)),
+            Some(Angle::new(1.0))
+        );
+        assert_eq!(
+            interpolate_stops(&stops, Ratio::new(1.0)),
+            Some(Angle::new(2.0))
+        );
+    }
+
+    #[test]
+    fn interpolate_matches_gradient() {
+        let transparent = color::BLUE.with_alpha(Ratio::new(0.0));
+        let gradient = crate::Gradient::linear(
+            vec![
+                crate::Stop::new(color::RED, Ratio::new(0.0)),
+                crate::Stop::new(transparent.clone(), Ratio::new(1.0)),
+            ],
+            Angle::new(0.0),
+            ColorSpace::Oklab,
+        );
+        let stops = [
+            (Ratio::new(0.0), color::RED),
+            (Ratio::new(1.0), transparent),
+        ];
+
+        let at = Ratio::new(0.3);
+        assert_eq!(interpolate_stops(&stops, at), Some(gradient.sample(at)));
+    }
+
+    #[test]
+    fn hard_stop() {
+        let stops = [
+            (Ratio::new(0.5), Ratio::new(0.0)),
+            (Ratio::new(0.5), Ratio::new(1.0)),
+        ];
+
+        assert_eq!(
+            interpolate_stops(&stops, Ratio::new(0.5)),
+            Some(Ratio::new(1.0))
+        );
+        assert_eq!(
+            interpolate_stops(&stops, Ratio::new(0.49)),
+            Some(Ratio::new(0.0))
+        );
+        assert_eq!(interpolate_stops::<Ratio>(&[], Ratio::new(0.5)), None);
+    }
+}
@@ -1,33 +1,49 @@
 pub use angle::Angle;
 pub use center::Center;
 pub use ciborium::Value;
-pub use color::{Cmyk, Color, ColorGradient, Hsl, Hsv, LinearRgb, Luma, Oklab, Oklch, Rgb};
+pub use color::{
+    BlendMode, Cmyk, Color, ColorGradient, Hsl, Hsv, LinearRgb, Luma, Oklab, Oklch, PaintKind, Rgb,
+    WcagLevel,
+};
 pub use content::Content;
 pub use datetime::{DateTime, DateTimeBuilder};
 pub use duration::{Duration, DurationBuilder};
 pub use gradient::{
-    ColorSpace, ConicGradientBuilder, Gradient, LinearGradientBuilder, RadialGradientBuilder,
+    ColorSpace, ConicGradientBuilder, Easing, Gradient, GradientBuilderError, GradientLut,
+    LinearGradientBuilder, PreparedGradient, RadialGradientBuilder, dedup_gradients,
 };
-pub use length::{Length, LengthRadius};
+pub use length::{Length, LengthRadius, LengthUnit};
+pub use lerp::{Lerp, interpolate_stops};
 pub use radius::Radius;
 pub use ratio::Ratio;
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 pub use stop::Stop;
+pub use tagged::Tagged;
 pub use r#type::Type;
+pub use validate::Validate;
+pub use value::TypedValue;
 pub use version::Version;
 
 mod angle;
 mod center;
 pub mod color;
 mod content;
+mod css;
 mod datetime;
 mod duration;
+#[cfg(feature = "fixed")]
+mod fixed;
 mod gradient;
 mod length;
+mod lerp;
 mod radius;
 mod ratio;
 mod stop;
+mod tagged;
 mod r#type;
+mod validate;
+mod value;
 mod version;
 
 /// A trait for types that can be deserialized from a byte slice.
@@ -47,8 +63,452 @@ pub trait FromBytes: Sized + DeserializeOwned {
     ///
     /// Returns an instance of the type on success or a string error message on failure.
     fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
-        ciborium::from_reader(bytes).map_err(|err| err.to_string())
+        ciborium::from_reader(bytes).map_err(de_error_message)
+    }
+
+    /// Deserializes an instance of a type implementing this trait from a generic CBOR value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A previously decoded CBOR value.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of the type on success or a string error message on failure.
+    fn from_value(value: Value) -> Result<Self, String> {
+        value.deserialized().map_err(value_error_message)
+    }
+
+    /// Deserializes an instance of a type implementing this trait from bincode-encoded bytes.
+    ///
+    /// Bincode is not self-describing, so only types that decode through a plain
+    /// struct shim (e.g. [`Angle`], [`Length`] or [`DateTime`]) are supported. Their
+    /// `typwire-type` tag is still validated. Types that inspect the decoded data to
+    /// pick a variant, such as [`Color`], [`Gradient`], [`Stop`] and [`ColorGradient`],
+    /// fail to decode.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - A byte slice containing bincode-encoded data.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of the type on success or a string error message on failure.
+    #[cfg(feature = "bincode")]
+    fn from_bincode(bytes: &[u8]) -> Result<Self, String> {
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map(|(value, _)| value)
+            .map_err(|err| err.to_string())
     }
 }
 
 impl<T: DeserializeOwned> FromBytes for T {}
+
+/// A trait for types that can be serialized into a byte vector.
+///
+/// This is the counterpart of [`FromBytes`], producing data that can be passed
+/// back to Typst.
+pub trait ToBytes: Serialize {
+    /// Serializes the instance into a byte vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns the serialized bytes on success or a string error message on failure.
+    fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes).map_err(ser_error_message)?;
+
+        Ok(bytes)
+    }
+
+    /// Serializes the instance into a generic CBOR value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the CBOR value on success or a string error message on failure.
+    fn to_value(&self) -> Result<Value, String> {
+        Value::serialized(self).map_err(value_error_message)
+    }
+
+    /// Serializes the instance into bincode-encoded bytes.
+    ///
+    /// See [`FromBytes::from_bincode`] for the types that can be decoded again.
+    ///
+    /// # Returns
+    ///
+    /// Returns the serialized bytes on success or a string error message on failure.
+    #[cfg(feature = "bincode")]
+    fn to_bincode(&self) -> Result<Vec<u8>, String> {
+        bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl<T: Serialize + ?Sized> ToBytes for T {}
+
+/// The key under which encoded values carry their type tag.
+const TYPE_KEY: &str = "typwire-type";
+
+/// A legacy spelling of [`TYPE_KEY`] that is still accepted when decoding.
+const LEGACY_TYPE_KEY: &str = "typed-type";
+
+/// Renames a legacy type tag key of a map value to the canonical one.
+///
+/// This is used by types whose tag is handled by serde directly, which
+/// cannot express an alias for the tag key itself.
+pub(crate) fn normalize_type_key(mut value: Value) -> Value {
+    if let Value::Map(entries) = &mut value {
+        let has_canonical = entries
+            .iter()
+            .any(|(key, _)| key.as_text() == Some(TYPE_KEY));
+
+        if !has_canonical {
+            for (key, _) in entries.iter_mut() {
+                if key.as_text() == Some(LEGACY_TYPE_KEY) {
+                    *key = Value::Text(TYPE_KEY.to_string());
+                }
+            }
+        }
+    }
+
+    value
+}
+
+/// Returns the `typwire-type` tag of a map value, accepting the legacy key.
+pub(crate) fn type_tag(value: &Value) -> Option<&str> {
+    let entries = value.as_map()?;
+    let tag = entries
+        .iter()
+        .find(|(key, _)| key.as_text() == Some(TYPE_KEY))
+        .or_else(|| {
+            entries
+                .iter()
+                .find(|(key, _)| key.as_text() == Some(LEGACY_TYPE_KEY))
+        })?;
+
+    tag.1.as_text()
+}
+
+/// Returns the message of a decoding error without the variant ciborium wraps
+/// it in, e.g. `Invalid color-rgb: ...` instead of `Semantic(None, "...")`.
+pub(crate) fn de_error_message<T: std::fmt::Debug>(error: ciborium::de::Error<T>) -> String {
+    match error {
+        ciborium::de::Error::Semantic(_, message) => message,
+        error => error.to_string(),
+    }
+}
+
+/// Returns the message of an encoding error without the variant ciborium wraps
+/// it in.
+pub(crate) fn ser_error_message<T: std::fmt::Debug>(error: ciborium::ser::Error<T>) -> String {
+    match error {
+        ciborium::ser::Error::Value(message) => message,
+        error => error.to_string(),
+    }
+}
+
+/// Returns the message of an error converting from or into a [`Value`].
+pub(crate) fn value_error_message(error: ciborium::value::Error) -> String {
+    let ciborium::value::Error::Custom(message) = error;
+    message
+}
+
+/// Rewrites a value into its canonical form for deterministic encoding.
+///
+/// Map keys are sorted in the canonical CBOR order of RFC 7049 (shorter keys
+/// first, then bytewise) and floats with several representations of the same
+/// value are unified: negative zero becomes zero and every NaN becomes the
+/// quiet NaN. ciborium already writes each float in its shortest lossless form.
+pub(crate) fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Map(entries) => {
+            let mut entries: Vec<_> = entries
+                .into_iter()
+                .map(|(key, value)| (canonicalize(key), canonicalize(value)))
+                .collect();
+            entries
+                .sort_by_cached_key(|(key, _)| ciborium::value::CanonicalValue::from(key.clone()));
+
+            Value::Map(entries)
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        Value::Tag(tag, value) => Value::Tag(tag, Box::new(canonicalize(*value))),
+        Value::Float(float) if float.is_nan() => Value::Float(f64::NAN),
+        // Also matches negative zero.
+        Value::Float(0.0) => Value::Float(0.0),
+        value => value,
+    }
+}
+
+/// Asserts that a decoded color equals the expected one, allowing for the
+/// rounding of the `fixed` encoding.
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn assert_decoded_color(decoded: &Color, expected: &Color) {
+    if cfg!(feature = "fixed") {
+        let close = decoded.space() == expected.space()
+            && (decoded.components().iter())
+                .zip(expected.components())
+                .all(|(a, b)| (a - b).abs() < 1e-3);
+        assert!(close, "{decoded:?} is not close to {expected:?}");
+    } else {
+        assert_eq!(decoded, expected);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(value: &Value) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    fn payload(key: &str, ty: &str, fields: Vec<(&str, Value)>) -> Vec<u8> {
+        let mut entries = vec![(Value::Text(key.into()), Value::Text(ty.into()))];
+        entries.extend(
+            fields
+                .into_iter()
+                .map(|(name, value)| (Value::Text(name.into()), value)),
+        );
+
+        encode(&Value::Map(entries))
+    }
+
+    /// Encodes a number like the fields of ratios, angles and lengths.
+    fn number(value: f64) -> Value {
+        if cfg!(feature = "fixed") {
+            Value::Integer(((value * 65536.0).round() as i64).into())
+        } else {
+            Value::Float(value)
+        }
+    }
+
+    fn ratio(key: &str, value: f64) -> Value {
+        ciborium::from_reader(payload(key, "ratio", vec![("ratio", number(value))]).as_slice())
+            .unwrap()
+    }
+
+    // All public types are plain data and therefore `Send + Sync`. This guards
+    // against accidentally introducing non-thread-safe fields.
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Angle>();
+        assert_send_sync::<Center>();
+        assert_send_sync::<Color>();
+        assert_send_sync::<ColorGradient>();
+        assert_send_sync::<Content>();
+        assert_send_sync::<DateTime>();
+        assert_send_sync::<DateTimeBuilder>();
+        assert_send_sync::<Duration>();
+        assert_send_sync::<DurationBuilder>();
+        assert_send_sync::<Gradient>();
+        assert_send_sync::<GradientBuilderError>();
+        assert_send_sync::<GradientLut>();
+        assert_send_sync::<LinearGradientBuilder>();
+        assert_send_sync::<RadialGradientBuilder>();
+        assert_send_sync::<ConicGradientBuilder>();
+        assert_send_sync::<Length>();
+        assert_send_sync::<LengthRadius>();
+        assert_send_sync::<Radius>();
+        assert_send_sync::<Ratio>();
+        assert_send_sync::<Stop>();
+        assert_send_sync::<Type>();
+        assert_send_sync::<TypedValue>();
+        assert_send_sync::<Version>();
+    }
+
+    #[test]
+    fn type_mismatch_names_both_types() {
+        let bytes = color::RED.to_bytes().unwrap();
+
+        let error = Duration::from_bytes(&bytes).unwrap_err();
+        assert_eq!(
+            error,
+            "Expected Duration, but payload is a Color (color-rgb)"
+        );
+
+        let error = Angle::from_value(Duration::new(1.0).to_value().unwrap()).unwrap_err();
+        assert_eq!(
+            error,
+            "Expected Angle, but payload is a Duration (duration)"
+        );
+
+        let legacy = payload(
+            LEGACY_TYPE_KEY,
+            "angle",
+            vec![("radians", Value::Float(1.0))],
+        );
+        let error = Length::from_bytes(&legacy).unwrap_err();
+        assert_eq!(error, "Expected Length, but payload is an Angle (angle)");
+    }
+
+    #[test]
+    fn type_mismatch_keeps_plain_errors() {
+        let malformed = payload(TYPE_KEY, "duration", vec![]);
+        assert!(
+            !Duration::from_bytes(&malformed)
+                .unwrap_err()
+                .starts_with("Expected")
+        );
+
+        let bytes = vec![Angle::new(1.0)].to_bytes().unwrap();
+        assert!(
+            !Vec::<Color>::from_bytes(&bytes)
+                .unwrap_err()
+                .starts_with("Expected")
+        );
+
+        // Only tagged types name the expected type.
+        let bytes = color::RED.to_bytes().unwrap();
+        assert!(
+            !Stop::from_bytes(&bytes)
+                .unwrap_err()
+                .starts_with("Expected")
+        );
+        assert!(ColorGradient::from_bytes(&bytes).is_ok());
+        assert!(TypedValue::from_bytes(&bytes).is_ok());
+
+        assert_eq!(
+            Angle::check_tag("unknown"),
+            Err("Invalid typwire-type for Angle: unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn value_roundtrip() {
+        let value = color::RED.to_value().unwrap();
+
+        assert_decoded_color(&Color::from_value(value).unwrap(), &color::RED);
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let bytes = color::RED.to_bytes().unwrap();
+
+        assert_decoded_color(&Color::from_bytes(&bytes).unwrap(), &color::RED);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_roundtrip() {
+        let angle = Angle::new(1.5);
+        let bytes = angle.to_bincode().unwrap();
+        assert_eq!(Angle::from_bincode(&bytes).unwrap(), angle);
+
+        let datetime = DateTime::builder().year(2025).month(6).build();
+        let bytes = datetime.to_bincode().unwrap();
+        assert_eq!(DateTime::from_bincode(&bytes).unwrap(), datetime);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_limitations() {
+        // The shim of a length has the same layout as an angle, but a different tag.
+        let bytes = Length::new(1.5).to_bincode().unwrap();
+        assert!(Angle::from_bincode(&bytes).is_err());
+
+        let bytes = color::RED.to_bincode().unwrap();
+        assert!(Color::from_bincode(&bytes).is_err());
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn fixed_roundtrip() {
+        let resolution = 1.0 / 65536.0;
+
+        let bytes = Ratio::new(1.0 / 3.0).to_bytes().unwrap();
+        let ratio = Ratio::from_bytes(&bytes).unwrap();
+        assert!((ratio.ratio - 1.0 / 3.0).abs() <= resolution / 2.0);
+
+        let bytes = Length::new(-12.345).to_bytes().unwrap();
+        let length = Length::from_bytes(&bytes).unwrap();
+        assert!((length.pt() + 12.345).abs() <= resolution / 2.0);
+
+        let bytes = color::RED.to_bytes().unwrap();
+        let Color::Rgb(rgb) = Color::from_bytes(&bytes).unwrap() else {
+            panic!("expected an rgb color");
+        };
+        assert!((rgb.g.ratio - 65.0 / 255.0).abs() <= resolution / 2.0);
+
+        let Value::Map(entries) = Angle::new(1.0).to_value().unwrap() else {
+            panic!("expected a map");
+        };
+        assert!(entries.contains(&(Value::Text("radians".into()), Value::Integer(65536.into()))));
+
+        let bytes = payload(TYPE_KEY, "angle", vec![("radians", Value::Float(0.1))]);
+        assert!(Angle::from_bytes(&bytes).is_err());
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(Angle);
+        let tag = schema.pointer("/properties/typwire-type/const");
+        assert_eq!(tag.and_then(|tag| tag.as_str()), Some("angle"));
+
+        let schema = schemars::schema_for!(Gradient).as_value().to_string();
+        assert!(schema.contains(r#""const":"gradient-radial""#));
+        assert!(schema.contains(r#""const":"color-oklch""#));
+        assert!(schema.contains(r#""const":"ratio""#));
+
+        // JSON encodes ratios as bare numbers, but accepts the tagged map too.
+        let schema = schemars::schema_for!(Ratio);
+        let variants = schema.get("anyOf").and_then(|variants| variants.as_array());
+        assert_eq!(variants.map(Vec::len), Some(2));
+        let schema = schema.as_value().to_string();
+        assert!(schema.contains(r#""type":"number""#));
+        assert!(schema.contains(r#""const":"ratio""#));
+    }
+
+    #[test]
+    fn legacy_type_key_angle() {
+        let fields = || vec![("radians", number(1.5))];
+
+        let legacy = Angle::from_bytes(&payload(LEGACY_TYPE_KEY, "angle", fields())).unwrap();
+        let current = Angle::from_bytes(&payload(TYPE_KEY, "angle", fields())).unwrap();
+
+        assert_eq!(legacy, current);
+    }
+
+    #[test]
+    fn legacy_type_key_color() {
+        let fields = |key| vec![("lightness", ratio(key, 0.5)), ("alpha", ratio(key, 1.0))];
+
+        let legacy = Color::from_bytes(&payload(
+            LEGACY_TYPE_KEY,
+            "color-luma",
+            fields(LEGACY_TYPE_KEY),
+        ))
+        .unwrap();
+        let current =
+            Color::from_bytes(&payload(TYPE_KEY, "color-luma", fields(TYPE_KEY))).unwrap();
+
+        assert_eq!(legacy, current);
+    }
+
+    #[test]
+    fn serialize_canonical_type_key() {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&color::BLACK, &mut bytes).unwrap();
+        let value: Value = ciborium::from_reader(bytes.as_slice()).unwrap();
+
+        let Value::Map(entries) = value else {
+            panic!("expected a map");
+        };
+        assert!(
+            entries
+                .iter()
+                .any(|(key, _)| key.as_text() == Some(TYPE_KEY))
+        );
+        assert!(
+            !entries
+                .iter()
+                .any(|(key, _)| key.as_text() == Some(LEGACY_TYPE_KEY))
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::Validate;
 use crate::length::Length;
+use crate::validate::Problems;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(from = "RadiusCbor", into = "RadiusCbor", rename_all = "kebab-case")]
 pub struct Radius {
     pub top_left: Option<Length>,
@@ -39,6 +42,74 @@ impl Radius {
             rest: None,
         }
     }
+
+    /// Applies `f` to every present corner, leaving absent corners unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, Radius};
+    ///
+    /// let radius = Radius::builder().top(Length::new(4.0)).build();
+    /// let doubled = radius.map(|length| Length::new(length.pt() * 2.0));
+    /// assert_eq!(doubled.top_left, Some(Length::new(8.0)));
+    /// assert_eq!(doubled.bottom_left, None);
+    /// ```
+    pub fn map<F: Fn(&Length) -> Length>(&self, f: F) -> Radius {
+        Radius::new(
+            self.top_left.as_ref().map(&f),
+            self.top_right.as_ref().map(&f),
+            self.bottom_left.as_ref().map(&f),
+            self.bottom_right.as_ref().map(&f),
+        )
+    }
+
+    /// Layers `other` over this radius, like a cascading style.
+    ///
+    /// Each corner takes the value of `other` if it is set and falls back to
+    /// this radius otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Length, Radius};
+    ///
+    /// let base = Radius::builder().rest(Length::new(4.0)).build();
+    /// let merged = base.merge(&Radius::builder().top_left(Length::new(0.0)).build());
+    /// assert_eq!(merged.top_left, Some(Length::new(0.0)));
+    /// assert_eq!(merged.top_right, Some(Length::new(4.0)));
+    /// ```
+    pub fn merge(&self, other: &Radius) -> Radius {
+        let corner =
+            |base: &Option<Length>, over: &Option<Length>| over.clone().or_else(|| base.clone());
+
+        Radius::new(
+            corner(&self.top_left, &other.top_left),
+            corner(&self.top_right, &other.top_right),
+            corner(&self.bottom_left, &other.bottom_left),
+            corner(&self.bottom_right, &other.bottom_right),
+        )
+    }
+}
+
+impl Validate for Radius {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+
+        let corners = [
+            ("top-left", &self.top_left),
+            ("top-right", &self.top_right),
+            ("bottom-left", &self.bottom_left),
+            ("bottom-right", &self.bottom_right),
+        ];
+        for (field, length) in corners {
+            if let Some(length) = length {
+                problems.nested(field, length);
+            }
+        }
+
+        problems.finish()
+    }
 }
 
 pub struct RadiusBuilder {
@@ -121,32 +192,87 @@ impl RadiusBuilder {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+/// Creates a uniform radius with all four corners set to the length.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Length, Radius};
+///
+/// let radius = Radius::from(Length::new(4.0));
+/// assert_eq!(radius.bottom_right, Some(Length::new(4.0)));
+/// ```
+impl From<Length> for Radius {
+    fn from(length: Length) -> Self {
+        Radius::builder().rest(length).build()
+    }
+}
+
+// Absent corners are omitted rather than encoded as `none`, which Typst would
+// prefer over a `rest` or side shorthand.
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct RadiusCbor {
+    #[serde(skip_serializing_if = "Option::is_none")]
     top_left: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     top_right: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     bottom_left: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     bottom_right: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     left: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     top: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     right: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     bottom: Option<Length>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     rest: Option<Length>,
 }
 
+// Serialize into the most compact equivalent RadiusCbor
 impl From<Radius> for RadiusCbor {
     fn from(value: Radius) -> Self {
-        Self {
-            top_left: value.top_left,
-            top_right: value.top_right,
-            bottom_left: value.bottom_left,
-            bottom_right: value.bottom_right,
-            left: None,
-            top: None,
-            right: None,
-            bottom: None,
-            rest: None,
+        let Radius {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        } = value;
+        let uniform_top = top_left.is_some() && top_left == top_right;
+        let uniform_bottom = bottom_left.is_some() && bottom_left == bottom_right;
+        let uniform_left = top_left.is_some() && top_left == bottom_left;
+        let uniform_right = top_right.is_some() && top_right == bottom_right;
+
+        if uniform_top && uniform_bottom && top_left == bottom_left {
+            Self {
+                rest: top_left,
+                ..Self::default()
+            }
+        } else if uniform_top && uniform_bottom {
+            Self {
+                top: top_left,
+                bottom: bottom_left,
+                ..Self::default()
+            }
+        } else if uniform_left && uniform_right {
+            Self {
+                left: top_left,
+                right: top_right,
+                ..Self::default()
+            }
+        } else {
+            Self {
+                top_left,
+                top_right,
+                bottom_left,
+                bottom_right,
+                ..Self::default()
+            }
         }
     }
 }
@@ -177,3 +303,106 @@ impl From<RadiusCbor> for Radius {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromBytes as _, ToBytes as _, Value};
+
+    fn keys(radius: &Radius) -> Vec<String> {
+        let bytes = radius.to_bytes().unwrap();
+        let Value::Map(entries) = Value::from_bytes(&bytes).unwrap() else {
+            panic!("expected a map");
+        };
+
+        entries
+            .into_iter()
+            .filter_map(|(key, _)| key.into_text().ok())
+            .collect()
+    }
+
+    #[test]
+    fn from_length() {
+        let radius = Radius::from(Length::new(3.0));
+        let corners = [
+            &radius.top_left,
+            &radius.top_right,
+            &radius.bottom_left,
+            &radius.bottom_right,
+        ];
+        assert!(
+            corners
+                .iter()
+                .all(|corner| **corner == Some(Length::new(3.0)))
+        );
+        assert_eq!(keys(&radius), ["rest"]);
+    }
+
+    #[test]
+    fn merge() {
+        let base = Radius::from(Length::new(4.0));
+        let merged = base.merge(&Radius::builder().top_left(Length::new(1.0)).build());
+
+        assert_eq!(merged.top_left, Some(Length::new(1.0)));
+        assert_eq!(merged.top_right, Some(Length::new(4.0)));
+        assert_eq!(merged.bottom_left, Some(Length::new(4.0)));
+        assert_eq!(merged.bottom_right, Some(Length::new(4.0)));
+
+        let empty = Radius::new(None, None, None, None);
+        assert_eq!(empty.merge(&empty).top_left, None);
+    }
+
+    #[test]
+    fn serialize_shorthand() {
+        let (a, b) = (Length::new(2.0), Length::new(5.0));
+        let cases = [
+            (Radius::builder().rest(a.clone()).build(), vec!["rest"]),
+            (
+                Radius::builder().top(a.clone()).bottom(b.clone()).build(),
+                vec!["top", "bottom"],
+            ),
+            (
+                Radius::builder().left(a.clone()).right(b.clone()).build(),
+                vec!["left", "right"],
+            ),
+            (
+                Radius::builder()
+                    .top_left(a.clone())
+                    .rest(b.clone())
+                    .build(),
+                vec!["top-left", "top-right", "bottom-left", "bottom-right"],
+            ),
+            (
+                Radius::builder().top_left(a.clone()).build(),
+                vec!["top-left"],
+            ),
+            (Radius::builder().build(), vec![]),
+        ];
+
+        for (radius, expected) in cases {
+            assert_eq!(keys(&radius), expected);
+
+            let decoded = Radius::from_bytes(&radius.to_bytes().unwrap()).unwrap();
+            assert_eq!(decoded.top_left, radius.top_left);
+            assert_eq!(decoded.top_right, radius.top_right);
+            assert_eq!(decoded.bottom_left, radius.bottom_left);
+            assert_eq!(decoded.bottom_right, radius.bottom_right);
+        }
+    }
+
+    #[test]
+    fn map_doubles_present_corners() {
+        let radius = Radius::new(
+            Some(Length::new(2.0)),
+            None,
+            Some(Length::new(5.0)),
+            Some(Length::new(0.0)),
+        );
+
+        let doubled = radius.map(|length| Length::new(length.pt() * 2.0));
+        assert_eq!(doubled.top_left, Some(Length::new(4.0)));
+        assert_eq!(doubled.top_right, None);
+        assert_eq!(doubled.bottom_left, Some(Length::new(10.0)));
+        assert_eq!(doubled.bottom_right, Some(Length::new(0.0)));
+    }
+}
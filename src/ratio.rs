@@ -1,10 +1,17 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-const TYPE_NAME: &str = "ratio";
+use crate::validate::Problems;
+use crate::{FromBytes as _, Tagged, Validate};
 
 /// A structure representing a ratio from 0 to 1.
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd, Debug)]
-#[serde(try_from = "RatioCbor", into = "RatioCbor")]
+///
+/// In CBOR, a ratio is encoded as a tagged map. Human-readable formats such as
+/// JSON encode it as a bare number instead, but also accept the tagged map, so
+/// its JSON schema allows either.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(with = "HumanReadableRatio"))]
 pub struct Ratio {
     pub ratio: f64,
 }
@@ -41,12 +48,121 @@ impl Ratio {
     pub fn to_percentage(&self) -> String {
         format!("{}%", self.ratio * 100.0)
     }
+
+    /// Creates a new `Ratio` from a byte channel value in the range `0..=255`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Ratio;
+    ///
+    /// assert_eq!(Ratio::from_u8(255), Ratio::new(1.0));
+    /// ```
+    pub const fn from_u8(value: u8) -> Self {
+        Self::new(value as f64 / 255.0)
+    }
+
+    /// Converts the ratio to a byte channel value, clamping it into `[0, 1]` and
+    /// rounding to the nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Ratio;
+    ///
+    /// assert_eq!(Ratio::new(0.5).to_u8(), 128);
+    /// assert_eq!(Ratio::new(1.5).to_u8(), 255);
+    /// ```
+    pub fn to_u8(&self) -> u8 {
+        (self.ratio.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
+    /// Snaps the ratio to `0` or `1` if it lies within `epsilon` of either.
+    ///
+    /// Other values are returned unchanged. This cleans up endpoints such as
+    /// `0.9999999` produced by floating-point arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Ratio;
+    ///
+    /// assert_eq!(Ratio::new(0.9999999).snapped(1e-6), Ratio::new(1.0));
+    /// assert_eq!(Ratio::new(0.5).snapped(1e-6), Ratio::new(0.5));
+    /// ```
+    pub fn snapped(&self, epsilon: f64) -> Ratio {
+        if self.ratio.abs() <= epsilon {
+            Ratio::new(0.0)
+        } else if (self.ratio - 1.0).abs() <= epsilon {
+            Ratio::new(1.0)
+        } else {
+            *self
+        }
+    }
+
+    /// Decodes a ratio from CBOR bytes, clamping it into `[0, 1]`.
+    ///
+    /// Regular decoding accepts any value, while this lets forgiving pipelines
+    /// repair out-of-range ratios and still notice that they did.
+    ///
+    /// # Returns
+    ///
+    /// Returns the clamped ratio and whether it had to be clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a string error message if the bytes are not a valid ratio or the
+    /// ratio is not finite, as NaN has no place in `[0, 1]` to clamp it to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, ToBytes as _};
+    ///
+    /// let bytes = Ratio::new(1.5).to_bytes().unwrap();
+    /// assert_eq!(Ratio::from_cbor_lenient(&bytes), Ok((Ratio::new(1.0), true)));
+    /// ```
+    pub fn from_cbor_lenient(bytes: &[u8]) -> Result<(Ratio, bool), String> {
+        let ratio = Self::from_bytes(bytes)?;
+        if !ratio.ratio.is_finite() {
+            return Err(format!("Ratio {} is not finite", ratio.ratio));
+        }
+
+        let clamped = Self::new(ratio.ratio.clamp(0.0, 1.0));
+
+        Ok((clamped, clamped.ratio != ratio.ratio))
+    }
+}
+
+impl Tagged for Ratio {
+    const TYPE_NAME: &'static str = "ratio";
+}
+
+impl Validate for Ratio {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.check(self.ratio.is_finite(), || {
+            format!("{} is not finite", self.ratio)
+        });
+        problems.finish()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct RatioCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Ratio, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Ratio>")
+    )]
     typwire_type: String,
+    #[cfg_attr(feature = "fixed", serde(with = "crate::fixed"))]
+    #[cfg_attr(all(feature = "fixed", feature = "schemars"), schemars(with = "i32"))]
     ratio: f64,
 }
 
@@ -54,7 +170,7 @@ struct RatioCbor {
 impl From<Ratio> for RatioCbor {
     fn from(value: Ratio) -> Self {
         RatioCbor {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Ratio::TYPE_NAME.to_string(),
             ratio: value.ratio,
         }
     }
@@ -65,14 +181,42 @@ impl TryFrom<RatioCbor> for Ratio {
     type Error = String;
 
     fn try_from(value: RatioCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for Ratio: {}",
-                value.typwire_type
-            ));
+        Ok(Self::new(value.ratio))
+    }
+}
+
+// Serialize into RatioCbor, or a bare number for human-readable formats
+impl Serialize for Ratio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(self.ratio)
+        } else {
+            RatioCbor::from(*self).serialize(serializer)
         }
+    }
+}
 
-        Ok(Self::new(value.ratio))
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum HumanReadableRatio {
+    Number(f64),
+    Tagged(RatioCbor),
+}
+
+// Deserialize from RatioCbor, or also a bare number for human-readable formats
+impl<'de> Deserialize<'de> for Ratio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cbor = if deserializer.is_human_readable() {
+            match HumanReadableRatio::deserialize(deserializer)? {
+                HumanReadableRatio::Number(ratio) => return Ok(Ratio::new(ratio)),
+                HumanReadableRatio::Tagged(cbor) => cbor,
+            }
+        } else {
+            RatioCbor::deserialize(deserializer)?
+        };
+
+        Ratio::try_from(cbor).map_err(D::Error::custom)
     }
 }
 
@@ -81,3 +225,85 @@ impl Default for Ratio {
         Ratio::new(1.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_readable() {
+        assert_eq!(serde_json::to_string(&Ratio::new(0.5)).unwrap(), "0.5");
+        assert_eq!(
+            serde_json::from_str::<Ratio>("0.5").unwrap(),
+            Ratio::new(0.5)
+        );
+
+        let tagged = serde_json::to_string(&RatioCbor::from(Ratio::new(0.5))).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Ratio>(&tagged).unwrap(),
+            Ratio::new(0.5)
+        );
+
+        // Nested ratios stay tagged maps in CBOR.
+        let value = crate::Value::serialized(&crate::color::RED).unwrap();
+        let crate::Value::Map(entries) = value else {
+            panic!("expected a map");
+        };
+        assert!(
+            entries
+                .iter()
+                .any(|(key, value)| key.as_text() == Some("r") && value.is_map())
+        );
+    }
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(Ratio::new(1.0).to_u8(), 255);
+        assert_eq!(Ratio::new(0.0).to_u8(), 0);
+    }
+
+    #[test]
+    fn from_u8() {
+        assert!((Ratio::from_u8(128).ratio - 0.502).abs() < 1e-3);
+    }
+
+    #[test]
+    fn snapped() {
+        assert_eq!(Ratio::new(0.9999999).snapped(1e-6), Ratio::new(1.0));
+        assert_eq!(Ratio::new(1.0000001).snapped(1e-6), Ratio::new(1.0));
+        assert_eq!(Ratio::new(-1e-9).snapped(1e-6), Ratio::new(0.0));
+        assert_eq!(Ratio::new(0.5).snapped(1e-6), Ratio::new(0.5));
+        assert_eq!(Ratio::new(0.999).snapped(1e-6), Ratio::new(0.999));
+    }
+
+    #[test]
+    fn from_cbor_lenient() {
+        use crate::ToBytes as _;
+
+        let bytes = Ratio::new(0.25).to_bytes().unwrap();
+        assert_eq!(
+            Ratio::from_cbor_lenient(&bytes),
+            Ok((Ratio::new(0.25), false))
+        );
+
+        let bytes = Ratio::new(-0.5).to_bytes().unwrap();
+        assert_eq!(
+            Ratio::from_cbor_lenient(&bytes),
+            Ok((Ratio::new(0.0), true))
+        );
+
+        assert!(Ratio::from_cbor_lenient(&[0xff]).is_err());
+    }
+
+    // Fixed-point ratios are always finite.
+    #[cfg(not(feature = "fixed"))]
+    #[test]
+    fn from_cbor_lenient_non_finite() {
+        use crate::ToBytes as _;
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let bytes = Ratio::new(value).to_bytes().unwrap();
+            assert!(Ratio::from_cbor_lenient(&bytes).is_err());
+        }
+    }
+}
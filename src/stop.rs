@@ -1,9 +1,13 @@
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{Color, Ratio};
+use crate::validate::Problems;
+use crate::{Color, ColorSpace, Ratio, Validate};
 
 /// A structure representing a color stop in a gradient with a specified color and offset.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Stop {
     pub color: Color,
     pub offset: Ratio,
@@ -29,4 +33,118 @@ impl Stop {
     pub const fn new(color: Color, offset: Ratio) -> Self {
         Self { color, offset }
     }
+
+    /// Returns whether the stop's offset lies within `[0, 1]`.
+    ///
+    /// A NaN offset is invalid. See [`Gradient::check`](crate::Gradient::check)
+    /// for locating the first invalid stop of a gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Stop, color};
+    ///
+    /// assert!(Stop::new(color::BLACK, Ratio::new(1.0)).is_valid());
+    /// assert!(!Stop::new(color::BLACK, Ratio::new(1.5)).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        (0.0..=1.0).contains(&self.offset.ratio)
+    }
+
+    /// Compares two stops by their offsets, imposing a total order.
+    ///
+    /// Unlike a partial comparison, this never fails: offsets are compared with
+    /// [`f64::total_cmp`], so stops with a NaN offset sort after all others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{Ratio, Stop, color};
+    ///
+    /// let mut stops = vec![
+    ///     Stop::new(color::WHITE, Ratio::new(1.0)),
+    ///     Stop::new(color::BLACK, Ratio::new(0.0)),
+    /// ];
+    /// stops.sort_by(Stop::cmp_offset);
+    /// assert_eq!(stops[0].color, color::BLACK);
+    /// ```
+    pub fn cmp_offset(a: &Stop, b: &Stop) -> Ordering {
+        a.offset.ratio.total_cmp(&b.offset.ratio)
+    }
+
+    /// Blends the colors of two stops, interpolating in the given color space.
+    ///
+    /// A `t` of `0` yields the color of `a` and a `t` of `1` the color of `b`;
+    /// the stops' offsets are ignored. See [`Color::mix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::{ColorSpace, Ratio, Stop, color};
+    ///
+    /// let a = Stop::new(color::RED, Ratio::new(0.0));
+    /// let b = Stop::new(color::BLUE, Ratio::new(1.0));
+    /// let color = Stop::interpolate(&a, &b, Ratio::new(0.0), ColorSpace::Rgb);
+    /// assert_eq!(color, color::RED);
+    /// ```
+    pub fn interpolate(a: &Stop, b: &Stop, t: Ratio, space: ColorSpace) -> Color {
+        a.color.mix(&b.color, t, space)
+    }
+}
+
+impl Validate for Stop {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.nested("color", &self.color);
+        problems.range("offset", self.offset.ratio, 0.0..=1.0);
+        problems.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Luma, color};
+
+    #[test]
+    fn is_valid() {
+        for (offset, valid) in [
+            (0.0, true),
+            (1.0, true),
+            (-0.1, false),
+            (1.5, false),
+            (f64::NAN, false),
+        ] {
+            assert_eq!(
+                Stop::new(color::BLACK, Ratio::new(offset)).is_valid(),
+                valid
+            );
+        }
+    }
+
+    #[test]
+    fn cmp_offset_nan() {
+        let mut stops = [
+            Stop::new(color::RED, Ratio::new(f64::NAN)),
+            Stop::new(color::WHITE, Ratio::new(1.0)),
+            Stop::new(color::BLACK, Ratio::new(0.0)),
+        ];
+        stops.sort_by(Stop::cmp_offset);
+
+        assert_eq!(stops[0].color, color::BLACK);
+        assert_eq!(stops[1].color, color::WHITE);
+        assert!(stops[2].offset.ratio.is_nan());
+    }
+
+    #[test]
+    fn interpolate() {
+        let black = Stop::new(color::BLACK, Ratio::new(0.0));
+        let white = Stop::new(color::WHITE, Ratio::new(1.0));
+
+        let color = Stop::interpolate(&black, &white, Ratio::new(0.25), ColorSpace::Luma);
+        assert_eq!(
+            color,
+            Color::Luma(Luma::new(Ratio::new(0.25), Ratio::new(1.0)))
+        );
+    }
 }
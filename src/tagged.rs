@@ -0,0 +1,69 @@
+use serde::{Deserialize, Deserializer, de};
+
+/// A trait for types that are encoded as a map carrying a `typwire-type` tag.
+///
+/// Implementors only declare their tag, validating it is shared by all of them.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Angle, Tagged};
+///
+/// assert_eq!(Angle::TYPE_NAME, "angle");
+/// assert!(Angle::check_tag("angle").is_ok());
+/// assert!(Angle::check_tag("length").is_err());
+/// ```
+pub trait Tagged {
+    /// The value of the `typwire-type` tag identifying this type.
+    const TYPE_NAME: &'static str;
+
+    /// Checks that a decoded tag matches [`Tagged::TYPE_NAME`].
+    ///
+    /// If the tag belongs to another known type, the error names both types.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The decoded value of the `typwire-type` tag.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the tag matches or a string error message otherwise.
+    fn check_tag(tag: &str) -> Result<(), String> {
+        if tag == Self::TYPE_NAME {
+            return Ok(());
+        }
+
+        let expected =
+            crate::value::type_for_tag(Self::TYPE_NAME).map_or(Self::TYPE_NAME, |(name, _)| name);
+
+        Err(match crate::value::type_for_tag(tag) {
+            Some((actual, _)) => {
+                let article = if actual.starts_with('A') { "an" } else { "a" };
+                format!("Expected {expected}, but payload is {article} {actual} ({tag})")
+            }
+            None => format!("Invalid typwire-type for {expected}: {tag}"),
+        })
+    }
+}
+
+/// Deserializes a `typwire-type` tag and checks that it belongs to `T`.
+///
+/// Checking the tag as soon as it is read reports a payload of another type
+/// before serde complains about its missing fields.
+pub(crate) fn deserialize_tag<'de, T: Tagged, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<String, D::Error> {
+    let tag = String::deserialize(deserializer)?;
+    T::check_tag(&tag).map_err(de::Error::custom)?;
+
+    Ok(tag)
+}
+
+/// Returns a JSON schema constraining a `typwire-type` tag to the name of `T`.
+#[cfg(feature = "schemars")]
+pub(crate) fn tag_schema<T: Tagged>(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "string",
+        "const": T::TYPE_NAME,
+    })
+}
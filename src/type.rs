@@ -1,9 +1,48 @@
 use serde::{Deserialize, Serialize};
 
-const TYPE_NAME: &str = "type";
+use crate::validate::Problems;
+use crate::{Angle, DateTime, Duration, Length, Ratio, Tagged, Validate, Version};
+
+/// The type names of the crate's own types followed by other common Typst types.
+const KNOWN_TYPES: &[&str] = &[
+    Angle::TYPE_NAME,
+    DateTime::TYPE_NAME,
+    Duration::TYPE_NAME,
+    Length::TYPE_NAME,
+    Ratio::TYPE_NAME,
+    Type::TYPE_NAME,
+    Version::TYPE_NAME,
+    "color",
+    "gradient",
+    "none",
+    "auto",
+    "bool",
+    "int",
+    "float",
+    "decimal",
+    "str",
+    "bytes",
+    "array",
+    "dictionary",
+    "arguments",
+    "function",
+    "module",
+    "content",
+    "label",
+    "regex",
+    "relative",
+    "fraction",
+    "alignment",
+    "direction",
+    "stroke",
+    "tiling",
+    "symbol",
+    "selector",
+];
 
 /// A structure representing a type defined by a string.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "TypeCbor", into = "TypeCbor")]
 pub struct Type {
     pub ty: String,
@@ -27,11 +66,61 @@ impl Type {
     pub const fn new(ty: String) -> Self {
         Self { ty }
     }
+
+    /// Returns whether the type is one of [`Type::known_types`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Type;
+    ///
+    /// assert!(Type::new(String::from("length")).is_known());
+    /// assert!(!Type::new(String::from("CustomType")).is_known());
+    /// ```
+    pub fn is_known(&self) -> bool {
+        KNOWN_TYPES.contains(&self.ty.as_str())
+    }
+
+    /// Returns the recognized type names: those of the crate's own types, such
+    /// as `angle` or `datetime`, and other common Typst types, such as `int` or
+    /// `content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Type;
+    ///
+    /// assert!(Type::known_types().contains(&"color"));
+    /// ```
+    pub const fn known_types() -> &'static [&'static str] {
+        KNOWN_TYPES
+    }
+}
+
+impl Tagged for Type {
+    const TYPE_NAME: &'static str = "type";
+}
+
+impl Validate for Type {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+        problems.check(!self.ty.is_empty(), || "type name is empty".to_string());
+        problems.finish()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct TypeCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Type, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Type>")
+    )]
     typwire_type: String,
     ty: String,
 }
@@ -40,7 +129,7 @@ struct TypeCbor {
 impl From<Type> for TypeCbor {
     fn from(value: Type) -> Self {
         Self {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Type::TYPE_NAME.to_string(),
             ty: value.ty,
         }
     }
@@ -51,10 +140,19 @@ impl TryFrom<TypeCbor> for Type {
     type Error = String;
 
     fn try_from(value: TypeCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!("Invalid typwire-type for Type: {}", value.typwire_type));
-        }
-
         Ok(Self::new(value.ty))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_known() {
+        assert!(Type::new("angle".into()).is_known());
+        assert!(Type::new("content".into()).is_known());
+        assert!(!Type::new("Angle".into()).is_known());
+        assert!(!Type::new("widget".into()).is_known());
+    }
+}
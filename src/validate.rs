@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+/// A trait for checking the invariants of a decoded value in a single pass.
+///
+/// Unlike decoding, which stops at the first error, validation accumulates
+/// every problem it finds. Each problem is prefixed with the path of the
+/// offending field, e.g. `stops[1]: offset: 1.5 is outside of [0, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Ratio, Stop, Validate, color};
+///
+/// assert!(Stop::new(color::BLACK, Ratio::new(0.5)).validate().is_ok());
+///
+/// let problems = Stop::new(color::BLACK, Ratio::new(1.5)).validate().unwrap_err();
+/// assert_eq!(problems, vec!["offset: 1.5 is outside of [0, 1]"]);
+/// ```
+pub trait Validate {
+    /// Checks all invariants of the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the value is valid or every problem found otherwise.
+    fn validate(&self) -> Result<(), Vec<String>>;
+}
+
+/// Accumulates validation problems.
+#[derive(Default)]
+pub(crate) struct Problems(Vec<String>);
+
+impl Problems {
+    /// Records `message` unless `valid` holds.
+    pub(crate) fn check(&mut self, valid: bool, message: impl FnOnce() -> String) {
+        if !valid {
+            self.0.push(message());
+        }
+    }
+
+    /// Records a problem if the field's value lies outside of `range`.
+    pub(crate) fn range<T: PartialOrd + Display>(
+        &mut self,
+        field: &str,
+        value: T,
+        range: RangeInclusive<T>,
+    ) {
+        self.check(range.contains(&value), || {
+            format!(
+                "{field}: {value} is outside of [{}, {}]",
+                range.start(),
+                range.end()
+            )
+        });
+    }
+
+    /// Validates a nested value, prefixing its problems with the field's path.
+    pub(crate) fn nested(&mut self, field: impl Display, value: &impl Validate) {
+        if let Err(problems) = value.validate() {
+            self.0.extend(
+                problems
+                    .into_iter()
+                    .map(|problem| format!("{field}: {problem}")),
+            );
+        }
+    }
+
+    /// Finishes validation, failing if any problem was recorded.
+    pub(crate) fn finish(self) -> Result<(), Vec<String>> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(self.0)
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Angle, Color, DateTime, Duration, FromBytes as _, Gradient, Length, Ratio, Tagged, Type, Value,
+    Version,
+};
+
+/// A value of any type that can be exchanged with Typst, decoded by its `typwire-type` tag.
+///
+/// This is the dynamic counterpart of the statically typed structs. Data without
+/// a tag, such as numbers, strings, arrays or plain dictionaries, is kept as
+/// [`TypedValue::Other`]. It is not named `Value` to avoid clashing with the
+/// CBOR [`Value`] it is decoded from.
+///
+/// # Examples
+///
+/// ```
+/// use typwire::{Angle, FromBytes as _, ToBytes as _, TypedValue};
+///
+/// let bytes = Angle::new(1.0).to_bytes().unwrap();
+/// let value = TypedValue::from_bytes(&bytes).unwrap();
+/// assert!(matches!(value, TypedValue::Angle(angle) if angle == Angle::new(1.0)));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged, try_from = "Value")]
+pub enum TypedValue {
+    /// An angle.
+    Angle(Angle),
+    /// A color of any color space.
+    Color(Color),
+    /// A gradient of any kind.
+    Gradient(Gradient),
+    /// A date, a time or both.
+    DateTime(DateTime),
+    /// A duration.
+    Duration(Duration),
+    /// A length.
+    Length(Length),
+    /// A ratio.
+    Ratio(Ratio),
+    /// A version.
+    Version(Version),
+    /// A type.
+    Type(Type),
+    /// Any value without a `typwire-type` tag.
+    Other(Value),
+}
+
+/// Decodes a tagged value into a [`TypedValue`].
+type Decode = fn(Value) -> Result<TypedValue, String>;
+
+/// Returns the name of the type that values with the given tag decode into,
+/// along with its decoder.
+pub(crate) fn type_for_tag(tag: &str) -> Option<(&'static str, Decode)> {
+    let entry: (&str, Decode) = match tag {
+        Angle::TYPE_NAME => ("Angle", |value| {
+            Angle::from_value(value).map(TypedValue::Angle)
+        }),
+        DateTime::TYPE_NAME => ("DateTime", |value| {
+            DateTime::from_value(value).map(TypedValue::DateTime)
+        }),
+        Duration::TYPE_NAME => ("Duration", |value| {
+            Duration::from_value(value).map(TypedValue::Duration)
+        }),
+        Length::TYPE_NAME => ("Length", |value| {
+            Length::from_value(value).map(TypedValue::Length)
+        }),
+        Ratio::TYPE_NAME => ("Ratio", |value| {
+            Ratio::from_value(value).map(TypedValue::Ratio)
+        }),
+        Version::TYPE_NAME => ("Version", |value| {
+            Version::from_value(value).map(TypedValue::Version)
+        }),
+        Type::TYPE_NAME => ("Type", |value| {
+            Type::from_value(value).map(TypedValue::Type)
+        }),
+        tag if tag.starts_with("color-") => ("Color", |value| {
+            Color::from_value(value).map(TypedValue::Color)
+        }),
+        tag if tag.starts_with("gradient-") => ("Gradient", |value| {
+            Gradient::from_value(value).map(TypedValue::Gradient)
+        }),
+        _ => return None,
+    };
+
+    Some(entry)
+}
+
+// Deserialize by dispatching on the type tag, accepting the legacy type key
+impl TryFrom<Value> for TypedValue {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let value = crate::normalize_type_key(value);
+
+        let Some(tag) = crate::type_tag(&value) else {
+            return Ok(Self::Other(value));
+        };
+
+        match type_for_tag(tag) {
+            Some((_, decode)) => decode(value),
+            None => Err(format!("Unknown typwire-type: {tag}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TYPE_KEY, ToBytes as _, color};
+
+    #[test]
+    fn decode_angle() {
+        let bytes = Angle::new(0.5).to_bytes().unwrap();
+
+        let value = TypedValue::from_bytes(&bytes).unwrap();
+        assert!(matches!(value, TypedValue::Angle(angle) if angle == Angle::new(0.5)));
+    }
+
+    #[test]
+    fn decode_color() {
+        let bytes = color::OLIVE.to_bytes().unwrap();
+
+        let TypedValue::Color(color) = TypedValue::from_bytes(&bytes).unwrap() else {
+            panic!("expected a color");
+        };
+        crate::assert_decoded_color(&color, &color::OLIVE);
+    }
+
+    #[test]
+    fn decode_untagged() {
+        let bytes = 42.to_bytes().unwrap();
+        assert!(matches!(
+            TypedValue::from_bytes(&bytes).unwrap(),
+            TypedValue::Other(Value::Integer(_))
+        ));
+
+        let bytes = Value::Map(vec![(
+            Value::Text(TYPE_KEY.to_string()),
+            Value::Text("fraction".to_string()),
+        )])
+        .to_bytes()
+        .unwrap();
+        assert!(TypedValue::from_bytes(&bytes).is_err());
+    }
+}
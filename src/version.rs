@@ -1,9 +1,13 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-const TYPE_NAME: &str = "version";
+use crate::validate::Problems;
+use crate::{Tagged, Validate};
 
 /// A structure representing version with 5 components.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(try_from = "VersionCbor", into = "VersionCbor")]
 pub struct Version {
     pub major: i64,
@@ -23,11 +27,110 @@ impl Version {
             build,
         }
     }
+
+    /// Formats the version without trailing zero components.
+    ///
+    /// At least `major.minor` is always kept. Use the [`Display`](fmt::Display)
+    /// implementation for all five components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typwire::Version;
+    ///
+    /// assert_eq!(Version::new(1, 2, 0, 0, 0).to_short_string(), "1.2");
+    /// assert_eq!(Version::new(1, 2, 0, 0, 0).to_string(), "1.2.0.0.0");
+    /// ```
+    pub fn to_short_string(&self) -> String {
+        let components = self.components();
+        let len = components
+            .iter()
+            .rposition(|&component| component != 0)
+            .map_or(0, |index| index + 1)
+            .max(2);
+
+        components[..len]
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    const fn components(&self) -> [i64; 5] {
+        [
+            self.major,
+            self.minor,
+            self.patch,
+            self.revision,
+            self.build,
+        ]
+    }
+}
+
+// Tuple conversions, filling missing trailing components with zero
+impl From<(i64, i64, i64)> for Version {
+    fn from((major, minor, patch): (i64, i64, i64)) -> Self {
+        Self::new(major, minor, patch, 0, 0)
+    }
+}
+
+impl From<(i64, i64, i64, i64)> for Version {
+    fn from((major, minor, patch, revision): (i64, i64, i64, i64)) -> Self {
+        Self::new(major, minor, patch, revision, 0)
+    }
+}
+
+impl From<(i64, i64, i64, i64, i64)> for Version {
+    fn from((major, minor, patch, revision, build): (i64, i64, i64, i64, i64)) -> Self {
+        Self::new(major, minor, patch, revision, build)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}.{}",
+            self.major, self.minor, self.patch, self.revision, self.build
+        )
+    }
+}
+
+impl Tagged for Version {
+    const TYPE_NAME: &'static str = "version";
+}
+
+impl Validate for Version {
+    fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Problems::default();
+
+        let components = [
+            ("major", self.major),
+            ("minor", self.minor),
+            ("patch", self.patch),
+            ("revision", self.revision),
+            ("build", self.build),
+        ];
+        for (field, value) in components {
+            problems.check(value >= 0, || format!("{field}: {value} is negative"));
+        }
+
+        problems.finish()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 struct VersionCbor {
+    #[serde(
+        alias = "typed-type",
+        deserialize_with = "crate::tagged::deserialize_tag::<Version, _>"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::tagged::tag_schema::<Version>")
+    )]
     typwire_type: String,
     major: i64,
     minor: i64,
@@ -40,7 +143,7 @@ struct VersionCbor {
 impl From<Version> for VersionCbor {
     fn from(value: Version) -> Self {
         Self {
-            typwire_type: TYPE_NAME.to_string(),
+            typwire_type: Version::TYPE_NAME.to_string(),
             major: value.major,
             minor: value.minor,
             patch: value.patch,
@@ -55,13 +158,6 @@ impl TryFrom<VersionCbor> for Version {
     type Error = String;
 
     fn try_from(value: VersionCbor) -> Result<Self, Self::Error> {
-        if value.typwire_type != TYPE_NAME {
-            return Err(format!(
-                "Invalid typwire-type for Version: {}",
-                value.typwire_type
-            ));
-        }
-
         Ok(Self::new(
             value.major,
             value.minor,
@@ -71,3 +167,22 @@ impl TryFrom<VersionCbor> for Version {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_tuples() {
+        assert_eq!(Version::from((1, 2, 3)), Version::new(1, 2, 3, 0, 0));
+        assert_eq!(Version::from((1, 2, 3, 4)), Version::new(1, 2, 3, 4, 0));
+        assert_eq!(Version::from((1, 2, 3, 4, 5)), Version::new(1, 2, 3, 4, 5));
+    }
+
+    #[test]
+    fn to_short_string() {
+        assert_eq!(Version::new(1, 2, 0, 0, 0).to_short_string(), "1.2");
+        assert_eq!(Version::new(1, 2, 3, 0, 5).to_short_string(), "1.2.3.0.5");
+        assert_eq!(Version::new(0, 0, 0, 0, 0).to_short_string(), "0.0");
+    }
+}